@@ -0,0 +1,211 @@
+use crate::error::ProviderError;
+use crate::models::common::{LinkKind, Milestone, Project, ProjectIssue, RemoteRef};
+use crate::providers::existing::ExistingEntities;
+use crate::state::ProjectState;
+use crate::traits::repository::RepositoryProvider;
+use crate::validation::{schedule_conflicts, validate_project, DependencyGraph, ScheduleConflict};
+
+/// Étape franchie par `setup`, transmise à l'observateur du moteur
+#[derive(Debug, Clone, Copy)]
+pub enum SetupEvent<'a> {
+    MilestoneCreated {
+        milestone: &'a Milestone,
+        remote: &'a RemoteRef,
+    },
+    /// Milestone déjà présent dans le repository ou dans l'état
+    MilestoneReused {
+        milestone: &'a Milestone,
+        id: i64,
+    },
+    IssueCreated {
+        issue: &'a ProjectIssue,
+        remote: &'a RemoteRef,
+    },
+    IssueReused {
+        issue: &'a ProjectIssue,
+        id: i64,
+    },
+    LinkCreated {
+        from: &'a ProjectIssue,
+        from_id: i64,
+        to: &'a str,
+        to_id: i64,
+        kind: LinkKind,
+    },
+}
+
+/// Résultat d'un `setup`
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SetupReport {
+    pub milestones_created: usize,
+    pub milestones_reused: usize,
+    pub issues_created: usize,
+    pub issues_reused: usize,
+    pub links_created: usize,
+    /// Dépendances vers des issues planifiées plus tard, voir [`schedule_conflicts`]
+    pub schedule_conflicts: Vec<ScheduleConflict>,
+}
+
+impl SetupReport {
+    pub fn summary(&self) -> String {
+        format!(
+            "{} milestones created ({} reused), {} issues created ({} reused), {} links created",
+            self.milestones_created,
+            self.milestones_reused,
+            self.issues_created,
+            self.issues_reused,
+            self.links_created
+        )
+    }
+}
+
+type Observer<'a> = Box<dyn Fn(&SetupEvent<'_>) + Send + Sync + 'a>;
+
+/// Moteur de `setup`, commun à tous les providers.
+///
+/// Le moteur ne s'appuie que sur les primitives de [`RepositoryProvider`] :
+/// milestones, puis issues, puis liens. Chaque entité est enregistrée dans
+/// l'état dès sa création, si bien qu'une erreur laisse un checkpoint
+/// depuis lequel reprendre.
+pub struct ProjectEngine<'a> {
+    provider: &'a dyn RepositoryProvider,
+    observer: Option<Observer<'a>>,
+}
+
+impl<'a> ProjectEngine<'a> {
+    pub fn new(provider: &'a dyn RepositoryProvider) -> Self {
+        Self {
+            provider,
+            observer: None,
+        }
+    }
+
+    /// Appelle `observer` à chaque étape, par exemple pour afficher la progression
+    pub fn on_event(mut self, observer: impl Fn(&SetupEvent<'_>) + Send + Sync + 'a) -> Self {
+        self.observer = Some(Box::new(observer));
+        self
+    }
+
+    fn emit(&self, event: SetupEvent<'_>) {
+        if let Some(observer) = &self.observer {
+            observer(&event);
+        }
+    }
+
+    /// Crée les milestones, issues et liens de `project` absents du repository.
+    ///
+    /// `state` contient les identifiants des entités créées lors des exécutions
+    /// précédentes et est complété au fur et à mesure des créations.
+    pub async fn setup(
+        &self,
+        project: &Project,
+        state: &mut ProjectState,
+    ) -> Result<SetupReport, ProviderError> {
+        // Valider le projet avant tout appel à l'API
+        validate_project(project).map_err(ProviderError::InvalidProject)?;
+
+        let mut report = SetupReport {
+            schedule_conflicts: schedule_conflicts(project),
+            ..SetupReport::default()
+        };
+
+        // Récupérer l'existant pour ne pas créer de doublons
+        let existing = ExistingEntities::for_setup(self.provider, state).await?;
+
+        // 1. Créer les milestones
+        for milestone in &project.milestones {
+            let id = match existing.milestone_id(milestone, state) {
+                Some(id) => {
+                    report.milestones_reused += 1;
+                    self.emit(SetupEvent::MilestoneReused { milestone, id });
+                    id
+                }
+                None => {
+                    let remote = self.provider.create_milestone(milestone).await?;
+                    report.milestones_created += 1;
+                    self.emit(SetupEvent::MilestoneCreated {
+                        milestone,
+                        remote: &remote,
+                    });
+                    remote.id
+                }
+            };
+            state.record_milestone(&milestone.version, id)?;
+        }
+
+        // 2. Créer les issues dans leur milestone, chacune après celles qu'elle attend
+        let order = DependencyGraph::new(project).topological_order();
+        for issue in order.into_iter().map(|index| &project.issues[index]) {
+            if let Some(id) = existing.issue_id(issue, state) {
+                state.record_issue(&issue.title, id)?;
+                report.issues_reused += 1;
+                self.emit(SetupEvent::IssueReused { issue, id });
+                continue;
+            }
+
+            // Validé plus haut et enregistré à l'étape précédente
+            let milestone = milestone_of(project, issue).ok_or_else(|| {
+                ProviderError::Config(format!("Milestone not found: {}", issue.milestone))
+            })?;
+            let milestone_id = state.milestone_id(&milestone.version).ok_or_else(|| {
+                ProviderError::Config(format!("Milestone not found: {}", milestone.version))
+            })?;
+
+            let remote = self
+                .provider
+                .create_project_issue(issue, milestone, milestone_id)
+                .await?;
+            state.record_issue(&issue.title, remote.id)?;
+            report.issues_created += 1;
+            self.emit(SetupEvent::IssueCreated {
+                issue,
+                remote: &remote,
+            });
+        }
+
+        // 3. Créer les liens entre les issues
+        for issue in &project.issues {
+            let Some(from_id) = state.issue_id(&issue.title) else {
+                continue;
+            };
+
+            for (to, kind) in issue.relations() {
+                if state.has_link(&issue.title, to, kind) {
+                    continue;
+                }
+                if let Some(to_id) = state.issue_id(to) {
+                    self.provider
+                        .create_issue_link(from_id, to_id, kind)
+                        .await?;
+                    state.record_link(&issue.title, to, kind)?;
+                    report.links_created += 1;
+                    self.emit(SetupEvent::LinkCreated {
+                        from: issue,
+                        from_id,
+                        to,
+                        to_id,
+                        kind,
+                    });
+                }
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+fn milestone_of<'p>(project: &'p Project, issue: &ProjectIssue) -> Option<&'p Milestone> {
+    project
+        .milestones
+        .iter()
+        .find(|milestone| milestone.version == issue.milestone)
+}
+
+/// Raccourci pour [`ProjectEngine::setup`] sans observateur
+pub async fn setup_project(
+    provider: &dyn RepositoryProvider,
+    project: &Project,
+    state: &mut ProjectState,
+) -> Result<SetupReport, ProviderError> {
+    ProjectEngine::new(provider).setup(project, state).await
+}
@@ -1,27 +1,39 @@
+use serde_json::Value;
+use std::fmt;
+use std::time::Duration;
 use thiserror::Error;
 
+use crate::validation::ValidationErrors;
+
 #[derive(Error, Debug)]
 pub enum ProviderError {
     #[error("API error: {0}")]
-    Api(String),
+    Api(Box<ApiError>),
 
     #[error("Configuration error: {0}")]
     Config(String),
 
+    /// Fichier projet incohérent, détecté avant tout appel à l'API
+    #[error("Invalid project: {0}")]
+    InvalidProject(ValidationErrors),
+
     #[error("Authentication error: {0}")]
-    Auth(String),
+    Auth(Box<ApiError>),
 
     #[error("Resource not found: {0}")]
-    NotFound(String),
+    NotFound(Box<ApiError>),
 
     #[error("Rate limit exceeded: {0}")]
-    RateLimit(String),
+    RateLimit(Box<ApiError>),
 
     #[error("Network error: {0}")]
     Network(String),
 
     #[error("Validation error: {0}")]
-    Validation(String),
+    Validation(Box<ApiError>),
+
+    #[error("Invalid response: {0}")]
+    Decode(String),
 
     #[error("JSON error: {0}")]
     Json(#[from] serde_json::Error),
@@ -29,3 +41,180 @@ pub enum ProviderError {
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 }
+
+/// Erreur de validation portant sur un champ, telle que décrite par le provider
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FieldError {
+    pub resource: Option<String>,
+    pub field: Option<String>,
+    pub code: Option<String>,
+    pub message: Option<String>,
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts: Vec<&str> = [&self.resource, &self.field, &self.code, &self.message]
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+            .collect();
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+/// Réponse en erreur d'un provider, avec la requête qui l'a provoquée
+#[derive(Debug, Clone)]
+pub struct ApiError {
+    pub status: u16,
+    pub method: String,
+    pub url: String,
+    /// Message décodé depuis le corps de la réponse
+    pub message: String,
+    pub field_errors: Vec<FieldError>,
+    /// Délai indiqué par le provider avant de réessayer, pour les limites de débit
+    pub retry_after: Option<Duration>,
+}
+
+impl ApiError {
+    pub fn new(
+        status: u16,
+        method: impl Into<String>,
+        url: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            status,
+            method: method.into(),
+            url: url.into(),
+            message: message.into(),
+            field_errors: Vec::new(),
+            retry_after: None,
+        }
+    }
+
+    /// Décode le corps d'une réponse en échec
+    pub fn from_response(status: u16, method: &str, url: &str, body: &str) -> Self {
+        let mut error = Self::new(status, method, url, String::new());
+        decode_body(body, &mut error);
+        error
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} returned {}: {}",
+            self.method, self.url, self.status, self.message
+        )?;
+
+        if !self.field_errors.is_empty() {
+            let fields: Vec<String> = self.field_errors.iter().map(|e| e.to_string()).collect();
+            write!(f, " ({})", fields.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ProviderError {
+    /// Construit l'erreur correspondant à une réponse HTTP en échec, en décodant
+    /// le corps au format GitHub (`message` + `errors`) ou GitLab (`message`
+    /// texte ou objet champ -> messages, `error_description`).
+    pub fn from_response(status: u16, method: &str, url: &str, body: &str) -> Self {
+        let error = Box::new(ApiError::from_response(status, method, url, body));
+
+        match status {
+            401 | 403 => ProviderError::Auth(error),
+            404 => ProviderError::NotFound(error),
+            400 | 422 => ProviderError::Validation(error),
+            429 => ProviderError::RateLimit(error),
+            _ => ProviderError::Api(error),
+        }
+    }
+
+    /// Détails de la réponse HTTP lorsque l'erreur en provient
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            ProviderError::Api(error)
+            | ProviderError::Auth(error)
+            | ProviderError::NotFound(error)
+            | ProviderError::RateLimit(error)
+            | ProviderError::Validation(error) => Some(error),
+            _ => None,
+        }
+    }
+
+    /// Code HTTP de la réponse en erreur
+    pub fn status(&self) -> Option<u16> {
+        self.api_error().map(|error| error.status)
+    }
+}
+
+fn decode_body(body: &str, error: &mut ApiError) {
+    let Ok(value) = serde_json::from_str::<Value>(body) else {
+        error.message = body.trim().chars().take(500).collect();
+        return;
+    };
+
+    match value.get("message") {
+        Some(Value::String(message)) => error.message = message.clone(),
+        // GitLab : {"message": {"name": ["has already been taken"]}}
+        Some(Value::Object(fields)) => {
+            for (field, messages) in fields {
+                let messages = match messages {
+                    Value::Array(messages) => messages.iter().map(value_to_string).collect(),
+                    other => vec![value_to_string(other)],
+                };
+                for message in messages {
+                    error.field_errors.push(FieldError {
+                        field: Some(field.clone()),
+                        message: Some(message),
+                        ..FieldError::default()
+                    });
+                }
+            }
+        }
+        _ => {}
+    }
+
+    // GitHub : {"errors": [{"resource": "Label", "field": "name", "code": "already_exists"}]}
+    if let Some(Value::Array(errors)) = value.get("errors") {
+        for item in errors {
+            let field = |name: &str| item.get(name).and_then(Value::as_str).map(str::to_string);
+            error.field_errors.push(match item {
+                Value::String(message) => FieldError {
+                    message: Some(message.clone()),
+                    ..FieldError::default()
+                },
+                _ => FieldError {
+                    resource: field("resource"),
+                    field: field("field"),
+                    code: field("code"),
+                    message: field("message"),
+                },
+            });
+        }
+    }
+
+    if error.message.is_empty() {
+        error.message = ["error_description", "error"]
+            .iter()
+            .find_map(|key| value.get(*key).and_then(Value::as_str))
+            .map(str::to_string)
+            .unwrap_or_else(|| {
+                if error.field_errors.is_empty() {
+                    body.trim().chars().take(500).collect()
+                } else {
+                    "Validation failed".to_string()
+                }
+            });
+    }
+}
+
+fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
@@ -0,0 +1,58 @@
+//! Gestionnaire de labels, milestones et issues pour GitHub, GitLab,
+//! Gitea/Forgejo, Bitbucket Cloud, Azure DevOps, Jira et un tracker local en
+//! fichiers Markdown.
+//!
+//! La bibliothèque expose le trait [`RepositoryProvider`], ses implémentations
+//! et les modèles partagés (`ProjectFile`, `Config`, ...) afin de pouvoir piloter
+//! un repository depuis un autre outil Rust. Le binaire `repo_manager` n'est
+//! qu'un client de cette API.
+//!
+//! ```no_run
+//! use repo_manager::{create_provider, Label, ProviderConfig, ProviderType};
+//!
+//! # async fn run() -> Result<(), repo_manager::ProviderError> {
+//! let config = ProviderConfig::builder()
+//!     .api_url("https://api.github.com")
+//!     .token("ghp_xxx")
+//!     .repository("owner/repo")
+//!     .build()?;
+//!
+//! let provider = create_provider(ProviderType::GitHub, config)?;
+//! provider
+//!     .create_label(&Label {
+//!         name: "type::bug".to_string(),
+//!         color: "#d73a4a".to_string(),
+//!         description: None,
+//!     })
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod engine;
+pub mod error;
+pub mod models;
+pub mod plan;
+pub mod providers;
+pub mod schedule;
+pub mod state;
+pub mod sync;
+pub mod traits;
+pub mod validation;
+
+#[cfg(test)]
+mod tests;
+
+pub use crate::error::ProviderError;
+pub use crate::models::common::{
+    Estimate, Issue, IssueCreate, IssueDescription, IssueFilter, IssueLink, IssueState, Label,
+    LinkKind, Milestone, Project, ProjectFile, ProjectIssue, RemoteIssue, RemoteMilestone,
+    RemoteRef, Section,
+};
+pub use crate::models::config::Config;
+pub use crate::providers::{
+    create_provider, AzureDevOpsProvider, BitbucketProvider, GitHubProvider, GitLabProvider,
+    GiteaProvider, InMemoryProvider, JiraProvider, LocalProvider, ProviderConfig,
+    ProviderConfigBuilder, ProviderType, RetryPolicy,
+};
+pub use crate::traits::repository::{DynProvider, RepositoryProvider};
@@ -1,14 +1,18 @@
-mod error;
-mod models;
-mod providers;
-mod traits;
-
-use clap::{Parser, Subcommand, ValueEnum};
-use error::ProviderError;
-use models::common::{Issue, IssueCreate, ProjectFile};
-use models::config::Config;
-use providers::{create_provider, ProviderConfig, ProviderType};
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
+use repo_manager::engine::{ProjectEngine, SetupEvent};
+use repo_manager::models::common::HOURS_PER_DAY;
+use repo_manager::plan::{plan_labels, plan_project, Plan};
+use repo_manager::schedule::{today, ScheduleOptions, ScheduleReport};
+use repo_manager::state::{StateFile, DEFAULT_STATE_PATH};
+use repo_manager::sync::{apply_label_changes, plan_label_changes};
+use repo_manager::validation::{schedule_conflicts, validate_project};
+use repo_manager::{
+    create_provider, Config, DynProvider, Issue, IssueCreate, IssueFilter, LocalProvider,
+    ProjectFile, ProviderConfig, ProviderError, ProviderType, RemoteRef, RetryPolicy,
+};
 use std::path::PathBuf;
+use std::time::Duration;
 
 #[derive(Debug, Copy, Clone, ValueEnum)]
 enum Provider {
@@ -16,6 +20,16 @@ enum Provider {
     GitHub,
     #[value(name = "gitlab")]
     GitLab,
+    #[value(name = "gitea", alias = "forgejo")]
+    Gitea,
+    #[value(name = "bitbucket")]
+    Bitbucket,
+    #[value(name = "azure-devops", alias = "azure")]
+    AzureDevOps,
+    #[value(name = "jira")]
+    Jira,
+    #[value(name = "local")]
+    Local,
 }
 
 impl From<Provider> for ProviderType {
@@ -23,6 +37,11 @@ impl From<Provider> for ProviderType {
         match provider {
             Provider::GitHub => ProviderType::GitHub,
             Provider::GitLab => ProviderType::GitLab,
+            Provider::Gitea => ProviderType::Gitea,
+            Provider::Bitbucket => ProviderType::Bitbucket,
+            Provider::AzureDevOps => ProviderType::AzureDevOps,
+            Provider::Jira => ProviderType::Jira,
+            Provider::Local => ProviderType::Local,
         }
     }
 }
@@ -30,21 +49,33 @@ impl From<Provider> for ProviderType {
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
 struct Cli {
-    /// Provider type (github or gitlab)
+    /// Provider type (github, gitlab, gitea, bitbucket, azure-devops, jira or local), required by every command except report
     #[arg(long, value_enum)]
-    provider: Provider,
+    provider: Option<Provider>,
 
-    /// API URL (e.g., https://api.github.com or https://gitlab.com/api/v4)
+    /// API URL (e.g., https://api.github.com, https://gitlab.com/api/v4 or https://forgejo.example.com/api/v1), not used by the local provider
     #[arg(long, env = "REPO_API_URL")]
-    api_url: String,
+    api_url: Option<String>,
 
-    /// Authentication token
+    /// Authentication token, not used by the local provider
     #[arg(long, env = "REPO_TOKEN")]
-    token: String,
+    token: Option<String>,
 
-    /// Repository identifier (e.g., "owner/repo" for GitHub or "group/project" for GitLab)
+    /// Repository identifier (e.g., "owner/repo" for GitHub, "group/project" for GitLab or "workspace/repo_slug" for Bitbucket or "organization/project" for Azure DevOps or "KEY[/board]" for Jira, or the output directory for the local provider)
     #[arg(long, env = "REPO_PATH")]
-    repository: String,
+    repository: Option<String>,
+
+    /// Maximum number of retries on server and network errors
+    #[arg(long, env = "REPO_MAX_RETRIES", default_value_t = 5)]
+    max_retries: u32,
+
+    /// Maximum total time in seconds to wait for rate limits before giving up
+    #[arg(long, env = "REPO_RATE_LIMIT_BUDGET", default_value_t = 300)]
+    rate_limit_budget: u64,
+
+    /// Print the changes that would be made without calling any write API
+    #[arg(long, alias = "plan", global = true)]
+    dry_run: bool,
 
     #[command(subcommand)]
     command: Commands,
@@ -55,8 +86,11 @@ enum Commands {
     /// Create repository labels
     Labels {
         /// Path to labels configuration file
-        #[arg(long, default_value = "labels.json")]
+        #[arg(long, default_value = "labels.json", global = true)]
         config: PathBuf,
+
+        #[command(subcommand)]
+        action: Option<LabelsAction>,
     },
     /// Create individual issues
     Issues {
@@ -69,9 +103,63 @@ enum Commands {
         /// Path to project configuration file
         #[arg(long, default_value = "project.json")]
         config: PathBuf,
+
+        /// Path to the state file mapping project entries to remote IDs
+        #[arg(long, default_value = DEFAULT_STATE_PATH)]
+        state: PathBuf,
+
+        /// Resume an interrupted setup from its last checkpoint
+        #[arg(long)]
+        resume: bool,
+    },
+    /// Reports computed from the project file, without calling any API
+    Report {
+        #[command(subcommand)]
+        report: ReportKind,
+    },
+}
+
+#[derive(Subcommand)]
+enum ReportKind {
+    /// Per-sprint and per-milestone load, critical path and deadline feasibility
+    Schedule {
+        /// Path to project configuration file
+        #[arg(long, default_value = "project.json")]
+        config: PathBuf,
+
+        /// First working day (YYYY-MM-DD), today by default
+        #[arg(long)]
+        start: Option<String>,
+
+        /// Hours of work for one story point
+        #[arg(long, default_value_t = HOURS_PER_DAY)]
+        hours_per_point: f64,
+
+        /// Print the report as JSON
+        #[arg(long)]
+        json: bool,
     },
 }
 
+#[derive(Subcommand)]
+enum LabelsAction {
+    /// Create missing labels, update drifted ones and optionally delete the others
+    Sync {
+        /// Delete repository labels that are not in the configuration file
+        #[arg(long)]
+        prune: bool,
+    },
+}
+
+/// Lien vers l'entité créée, à ajouter en fin de ligne
+fn link(remote: &RemoteRef) -> String {
+    remote
+        .web_url
+        .as_ref()
+        .map(|url| format!(" → {}", url))
+        .unwrap_or_default()
+}
+
 #[tokio::main]
 async fn main() -> Result<(), ProviderError> {
     // Charger les variables d'environnement
@@ -80,34 +168,138 @@ async fn main() -> Result<(), ProviderError> {
     // Parser les arguments
     let cli = Cli::parse();
 
+    // Les rapports ne lisent que le fichier projet
+    if let Commands::Report {
+        report:
+            ReportKind::Schedule {
+                config,
+                start,
+                hours_per_point,
+                json,
+            },
+    } = &cli.command
+    {
+        let content = std::fs::read_to_string(config)?;
+        let project_file: ProjectFile = serde_json::from_str(&content)?;
+        let options = ScheduleOptions {
+            start: start.clone().unwrap_or_else(today),
+            hours_per_point: *hours_per_point,
+        };
+
+        match ScheduleReport::for_project(&project_file.project, &options) {
+            Ok(report) if *json => println!("{}", serde_json::to_string_pretty(&report)?),
+            Ok(report) => println!("{}", report),
+            Err(e) => {
+                eprintln!("❌ Failed to compute schedule: {}", e);
+                return Err(e);
+            }
+        }
+        return Ok(());
+    }
+
+    let (Some(cli_provider), Some(repository)) = (cli.provider, cli.repository.clone()) else {
+        Cli::command()
+            .error(
+                ErrorKind::MissingRequiredArgument,
+                "--provider and --repository are required",
+            )
+            .exit();
+    };
+
     println!(
         "Using {} provider",
-        match cli.provider {
+        match cli_provider {
             Provider::GitHub => "GitHub",
             Provider::GitLab => "GitLab",
+            Provider::Gitea => "Gitea",
+            Provider::Bitbucket => "Bitbucket",
+            Provider::AzureDevOps => "Azure DevOps",
+            Provider::Jira => "Jira",
+            Provider::Local => "local",
         }
     );
 
-    // Créer la configuration du provider
-    let config = ProviderConfig {
-        api_url: cli.api_url,
-        token: cli.token,
-        repository: cli.repository,
-    };
+    let provider_type: ProviderType = cli_provider.into();
+    let state_key = StateFile::key(provider_type.name(), &repository);
+
+    // Créer le provider ; le provider local n'appelle aucune API
+    let provider: DynProvider = match provider_type {
+        ProviderType::Local => Box::new(LocalProvider::new(&repository)),
+        _ => {
+            let mut builder = ProviderConfig::builder()
+                .repository(repository)
+                .retry_policy(RetryPolicy {
+                    max_retries: cli.max_retries,
+                    rate_limit_budget: Duration::from_secs(cli.rate_limit_budget),
+                    ..RetryPolicy::default()
+                });
+            if let Some(api_url) = cli.api_url {
+                builder = builder.api_url(api_url);
+            }
+            if let Some(token) = cli.token {
+                builder = builder.token(token);
+            }
 
-    // Créer le provider
-    let provider = create_provider(cli.provider.into(), config)?;
+            create_provider(provider_type, builder.build()?)?
+        }
+    };
 
     // Exécuter la commande appropriée
     match cli.command {
-        Commands::Labels { config } => {
+        Commands::Labels {
+            config,
+            action: Some(LabelsAction::Sync { prune }),
+        } => {
             println!("Loading labels from: {}", config.display());
             let config = Config::from_file(config.to_str().unwrap())?;
 
+            if prune && !provider.supports_label_deletion() {
+                eprintln!("⚠️  This provider cannot delete labels, --prune is ignored");
+            }
+            let changes = plan_label_changes(provider.as_ref(), &config.labels, prune).await?;
+
+            if cli.dry_run {
+                println!("{}", Plan::from_label_changes(&changes));
+                return Ok(());
+            }
+
+            let report = apply_label_changes(provider.as_ref(), changes).await;
+
+            for name in &report.created {
+                println!("✅ Created label: {}", name);
+            }
+            for name in &report.updated {
+                println!("🔄 Updated label: {}", name);
+            }
+            for name in &report.deleted {
+                println!("🗑️  Deleted label: {}", name);
+            }
+            for (name, e) in &report.failed {
+                eprintln!("❌ Failed to sync label {}: {}", name, e);
+            }
+
+            println!("Label sync: {}", report.summary());
+        }
+        Commands::Labels {
+            config,
+            action: None,
+        } => {
+            println!("Loading labels from: {}", config.display());
+            let config = Config::from_file(config.to_str().unwrap())?;
+
+            if cli.dry_run {
+                println!("{}", plan_labels(provider.as_ref(), &config.labels).await?);
+                return Ok(());
+            }
+
             for label in config.labels {
                 match provider.create_label(&label).await {
-                    Ok(_) => println!("✅ Created label: {}", label.name),
-                    Err(e) => eprintln!("❌ Failed to create label {}: {:?}", label.name, e),
+                    Ok(remote) => println!(
+                        "✅ Created label: {}{}",
+                        label.name,
+                        remote.as_ref().map(link).unwrap_or_default()
+                    ),
+                    Err(e) => eprintln!("❌ Failed to create label {}: {}", label.name, e),
                 }
             }
         }
@@ -116,32 +308,131 @@ async fn main() -> Result<(), ProviderError> {
             let content = std::fs::read_to_string(tasks)?;
             let issues: Vec<Issue> = serde_json::from_str(&content)?;
 
-            for issue in issues {
-                let description = issue.description.to_markdown();
-                let create_issue = IssueCreate {
-                    title: issue.title.clone(),
-                    description,
+            let issues: Vec<IssueCreate> = issues
+                .into_iter()
+                .map(|issue| IssueCreate {
+                    description: issue.description.to_markdown(),
+                    title: issue.title,
                     labels: issue.labels,
-                };
+                    milestone: issue.milestone,
+                    milestone_id: None,
+                    assignees: issue.assignees,
+                    due_date: issue.due_date,
+                    weight: issue.weight,
+                })
+                .collect();
+
+            if cli.dry_run {
+                let existing = provider.list_issues(&IssueFilter::default()).await?;
+                println!("{}", Plan::for_issues(&issues, &existing));
+                return Ok(());
+            }
 
+            for create_issue in issues {
                 match provider.create_issue(&create_issue).await {
-                    Ok(_) => println!("✅ Created issue: {}", create_issue.title),
+                    Ok(remote) => println!(
+                        "✅ Created issue: {} (#{}){}",
+                        create_issue.title,
+                        remote.id,
+                        link(&remote)
+                    ),
                     Err(e) => {
-                        eprintln!("❌ Failed to create issue {}: {:?}", create_issue.title, e)
+                        eprintln!("❌ Failed to create issue {}: {}", create_issue.title, e)
                     }
                 }
             }
         }
-        Commands::Setup { config } => {
+        Commands::Setup {
+            config,
+            state,
+            resume,
+        } => {
             println!("Loading project from: {}", config.display());
             let content = std::fs::read_to_string(config)?;
             let project_file: ProjectFile = serde_json::from_str(&content)?;
 
-            match provider.setup_project(&project_file.project).await {
-                Ok(_) => println!("✅ Project setup completed successfully!"),
-                Err(e) => eprintln!("❌ Failed to setup project: {:?}", e),
+            if let Err(errors) = validate_project(&project_file.project) {
+                eprintln!("❌ Invalid project file: {}", errors);
+                return Err(ProviderError::InvalidProject(errors));
+            }
+            for conflict in schedule_conflicts(&project_file.project) {
+                println!("⚠️  Scheduled too late: {}", conflict);
+            }
+
+            if cli.dry_run {
+                let project_state = StateFile::load(&state)?
+                    .project(&state_key)
+                    .cloned()
+                    .unwrap_or_default();
+                let plan =
+                    plan_project(provider.as_ref(), &project_file.project, &project_state).await?;
+                println!("{}", plan);
+                return Ok(());
+            }
+
+            // Chaque entité créée est enregistrée immédiatement dans le fichier d'état
+            let mut project_state = StateFile::open_project(&state, &state_key)?;
+
+            if resume {
+                println!(
+                    "Resuming setup: {} milestones, {} issues and {} links already done",
+                    project_state.milestones.len(),
+                    project_state.issues.len(),
+                    project_state.links.len()
+                );
+            } else if project_state.is_interrupted() {
+                println!("⚠️  Previous setup was interrupted, checking existing entities");
+            }
+
+            project_state.begin(resume)?;
+
+            let engine = ProjectEngine::new(provider.as_ref()).on_event(|event| match event {
+                SetupEvent::MilestoneCreated { milestone, remote } => println!(
+                    "✅ Created milestone: {} ({}){}",
+                    milestone.name,
+                    remote.id,
+                    link(remote)
+                ),
+                SetupEvent::MilestoneReused { milestone, id } => {
+                    println!("♻️  Reusing milestone: {} ({})", milestone.name, id)
+                }
+                SetupEvent::IssueCreated { issue, remote } => println!(
+                    "✅ Created issue: {} (#{}){}",
+                    issue.title,
+                    remote.id,
+                    link(remote)
+                ),
+                SetupEvent::IssueReused { issue, id } => {
+                    println!("♻️  Reusing issue: {} (#{})", issue.title, id)
+                }
+                SetupEvent::LinkCreated {
+                    from_id,
+                    to_id,
+                    kind,
+                    ..
+                } => println!("🔗 Linked #{} to #{} ({})", from_id, to_id, kind.name()),
+            });
+
+            match engine
+                .setup(&project_file.project, &mut project_state)
+                .await
+            {
+                Ok(report) => {
+                    project_state.complete()?;
+                    println!("Setup: {}", report.summary());
+                    println!("✅ Project setup completed successfully!");
+                }
+                Err(e) => {
+                    eprintln!("❌ Failed to setup project: {}", e);
+                    eprintln!(
+                        "Progress saved to {}, run again with --resume to continue",
+                        state.display()
+                    );
+                }
             }
         }
+        // Traité avant la création du provider
+        Commands::Report { .. } => {}
     }
 
     Ok(())
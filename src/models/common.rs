@@ -33,6 +33,17 @@ pub struct Issue {
     pub title: String,
     pub labels: Vec<String>,
     pub description: IssueDescription,
+    /// Titre du milestone
+    #[serde(default)]
+    pub milestone: Option<String>,
+    /// Noms d'utilisateur des personnes assignées
+    #[serde(default)]
+    pub assignees: Vec<String>,
+    /// Échéance au format YYYY-MM-DD
+    #[serde(default)]
+    pub due_date: Option<String>,
+    #[serde(default)]
+    pub weight: Option<u32>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -41,11 +52,65 @@ pub struct ProjectIssue {
     pub milestone: String,
     pub estimate: String,
     pub sprint: u32,
+    /// Titres des issues qui bloquent celle-ci (liens `blocked-by`)
     pub dependencies: Vec<String>,
+    /// Autres liens vers des issues du projet
+    #[serde(default)]
+    pub links: Vec<IssueLink>,
     pub labels: Vec<String>,
     pub description: IssueDescription,
 }
 
+/// Nature d'un lien entre deux issues, qui se lit « source `kind` cible »
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum LinkKind {
+    /// La source bloque la cible
+    Blocks,
+    /// La source est bloquée par la cible, dont elle dépend
+    #[default]
+    BlockedBy,
+    /// Lien sans contrainte d'ordre
+    RelatesTo,
+    /// La source est le parent de la cible
+    Parent,
+    /// La source est un enfant de la cible
+    Child,
+}
+
+impl LinkKind {
+    /// Nom utilisé dans le fichier projet
+    pub fn name(&self) -> &'static str {
+        match self {
+            LinkKind::Blocks => "blocks",
+            LinkKind::BlockedBy => "blocked-by",
+            LinkKind::RelatesTo => "relates-to",
+            LinkKind::Parent => "parent",
+            LinkKind::Child => "child",
+        }
+    }
+
+    /// Mention du lien vers l'issue `#id`, pour les providers qui le notent en commentaire
+    pub fn describe(&self, id: i64) -> String {
+        match self {
+            LinkKind::Blocks => format!("Blocks #{}", id),
+            LinkKind::BlockedBy => format!("Depends on #{}", id),
+            LinkKind::RelatesTo => format!("Related to #{}", id),
+            LinkKind::Parent => format!("Parent of #{}", id),
+            LinkKind::Child => format!("Child of #{}", id),
+        }
+    }
+}
+
+/// Lien typé vers une autre issue du fichier projet
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct IssueLink {
+    /// Titre de l'issue cible
+    pub title: String,
+    #[serde(default)]
+    pub kind: LinkKind,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct IssueDescription {
     pub sections: Vec<Section>,
@@ -57,11 +122,171 @@ pub struct Section {
     pub content: Vec<String>,
 }
 
-#[derive(Debug, Serialize)]
+/// Issue à créer.
+///
+/// Le milestone est désigné par son titre et les personnes assignées par leur
+/// nom d'utilisateur ; chaque provider les résout en identifiants. GitHub ne
+/// gère ni échéance ni poids ; les autres champs qu'un provider ne sait pas
+/// renseigner sont refusés par une [`crate::error::ProviderError::Config`].
+#[derive(Debug, Serialize, Default)]
 pub struct IssueCreate {
     pub title: String,
     pub description: String,
     pub labels: Vec<String>,
+    pub milestone: Option<String>,
+    /// Identifiant du milestone déjà résolu, qui dispense de rechercher `milestone`
+    pub milestone_id: Option<i64>,
+    pub assignees: Vec<String>,
+    /// Échéance au format YYYY-MM-DD
+    pub due_date: Option<String>,
+    pub weight: Option<u32>,
+}
+
+/// État d'une issue ou d'un milestone côté provider
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum IssueState {
+    Open,
+    Closed,
+}
+
+/// Milestone tel qu'il existe dans le repository distant
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RemoteMilestone {
+    pub id: i64,
+    pub title: String,
+    pub description: Option<String>,
+    pub due_date: Option<String>,
+    pub state: IssueState,
+}
+
+/// Issue telle qu'elle existe dans le repository distant.
+///
+/// `id` est l'identifiant attendu par `create_issue_link` et `get_issue`
+/// (le `number` sur GitHub, l'`iid` sur GitLab).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RemoteIssue {
+    pub id: i64,
+    pub title: String,
+    pub description: String,
+    pub state: IssueState,
+    pub labels: Vec<String>,
+    pub milestone: Option<String>,
+    pub web_url: String,
+}
+
+/// Référence vers une entité créée chez le provider.
+///
+/// `id` est l'identifiant accepté par les autres méthodes du trait : le
+/// `number` d'une issue GitHub, l'`iid` d'une issue GitLab, l'ID d'un milestone...
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct RemoteRef {
+    pub id: i64,
+    /// Identifiant global, lorsqu'il diffère de `id` (GitHub, GitLab, Gitea)
+    pub global_id: Option<i64>,
+    /// Page de l'entité dans l'interface web
+    pub web_url: Option<String>,
+    /// Identifiant GraphQL (GitHub)
+    pub node_id: Option<String>,
+}
+
+impl RemoteRef {
+    pub fn new(id: i64) -> Self {
+        Self {
+            id,
+            global_id: None,
+            web_url: None,
+            node_id: None,
+        }
+    }
+
+    pub fn with_web_url(mut self, web_url: impl Into<String>) -> Self {
+        self.web_url = Some(web_url.into());
+        self
+    }
+}
+
+/// Filtres applicables à `list_issues`. Un filtre vide retourne toutes les issues.
+#[derive(Debug, Default, Clone)]
+pub struct IssueFilter {
+    pub state: Option<IssueState>,
+    pub labels: Vec<String>,
+    /// Titre du milestone
+    pub milestone: Option<String>,
+}
+
+/// Nombre d'heures travaillées dans une journée d'estimation
+pub const HOURS_PER_DAY: f64 = 8.0;
+
+/// Estimation d'une issue, telle qu'écrite dans le fichier projet
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Estimate {
+    /// Durée (`4h`, `2d`, `1w`), convertie en heures
+    Hours(f64),
+    /// Nombre sans unité, interprété comme des story points
+    Points(f64),
+}
+
+impl Estimate {
+    /// Interprète une estimation ; `None` si elle est vide ou invalide
+    pub fn parse(estimate: &str) -> Option<Self> {
+        let estimate = estimate.trim().to_lowercase();
+        let split = estimate
+            .find(|c: char| c.is_ascii_alphabetic())
+            .unwrap_or(estimate.len());
+        let (value, unit) = estimate.split_at(split);
+        let value: f64 = value.trim().replace(',', ".").parse().ok()?;
+
+        if value < 0.0 {
+            return None;
+        }
+
+        match unit.trim() {
+            "" | "pt" | "pts" | "sp" => Some(Estimate::Points(value)),
+            "h" => Some(Estimate::Hours(value)),
+            "d" | "j" => Some(Estimate::Hours(value * HOURS_PER_DAY)),
+            "w" => Some(Estimate::Hours(value * 5.0 * HOURS_PER_DAY)),
+            _ => None,
+        }
+    }
+}
+
+/// Début du marqueur invisible ajouté au corps des issues créées par `setup`
+pub const ISSUE_MARKER_PREFIX: &str = "<!-- repo_manager:issue ";
+
+impl ProjectIssue {
+    /// Liens de l'issue : ses dépendances, puis ses liens typés
+    pub fn relations(&self) -> impl Iterator<Item = (&str, LinkKind)> {
+        self.dependencies
+            .iter()
+            .map(|title| (title.as_str(), LinkKind::BlockedBy))
+            .chain(
+                self.links
+                    .iter()
+                    .map(|link| (link.title.as_str(), link.kind)),
+            )
+    }
+
+    /// Estimation interprétée, `None` si elle n'est pas reconnue
+    pub fn parsed_estimate(&self) -> Option<Estimate> {
+        Estimate::parse(&self.estimate)
+    }
+
+    /// Commentaire HTML invisible permettant de retrouver l'issue même si son titre
+    /// a été modifié dans le provider
+    pub fn marker(&self) -> String {
+        // "--" fermerait le commentaire HTML prématurément
+        format!(
+            "{}{} -->",
+            ISSUE_MARKER_PREFIX,
+            self.title.replace("--", "-")
+        )
+    }
+
+    /// Corps de l'issue : description suivie du marqueur
+    pub fn to_markdown(&self) -> String {
+        format!("{}\n{}", self.description.to_markdown(), self.marker())
+    }
 }
 
 impl IssueDescription {
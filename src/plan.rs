@@ -0,0 +1,253 @@
+use std::fmt;
+
+use crate::error::ProviderError;
+use crate::models::common::{IssueCreate, Label, LinkKind, Project, RemoteIssue};
+use crate::providers::existing::ExistingEntities;
+use crate::state::ProjectState;
+use crate::sync::{normalize_labels, LabelChange};
+use crate::traits::repository::RepositoryProvider;
+use crate::validation::{validate_project, DependencyGraph};
+
+/// Type d'opération prévue, à la manière d'un plan Terraform
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Create,
+    Update,
+    Delete,
+    /// L'élément existe déjà et ne sera pas modifié
+    Keep,
+}
+
+impl Action {
+    fn symbol(self) -> char {
+        match self {
+            Action::Create => '+',
+            Action::Update => '~',
+            Action::Delete => '-',
+            Action::Keep => '=',
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PlannedChange {
+    pub action: Action,
+    pub description: String,
+}
+
+/// Ensemble des opérations qu'une commande effectuerait, sans appel aux APIs d'écriture
+#[derive(Debug, Clone, Default)]
+pub struct Plan {
+    pub changes: Vec<PlannedChange>,
+}
+
+impl Plan {
+    fn push(&mut self, action: Action, description: String) {
+        self.changes.push(PlannedChange {
+            action,
+            description,
+        });
+    }
+
+    pub fn count(&self, action: Action) -> usize {
+        self.changes.iter().filter(|c| c.action == action).count()
+    }
+
+    pub fn has_changes(&self) -> bool {
+        self.changes.iter().any(|c| c.action != Action::Keep)
+    }
+
+    /// Plan de `labels sync`
+    pub fn from_label_changes(changes: &[LabelChange]) -> Self {
+        let mut plan = Plan::default();
+
+        for change in changes {
+            match change {
+                LabelChange::Create(label) => {
+                    plan.push(Action::Create, format!("label {}", describe_label(label)))
+                }
+                LabelChange::Update { current, desired } => plan.push(
+                    Action::Update,
+                    format!(
+                        "label {} -> {}",
+                        describe_label(current),
+                        describe_label(desired)
+                    ),
+                ),
+                LabelChange::Delete(label) => {
+                    plan.push(Action::Delete, format!("label \"{}\"", label.name))
+                }
+                LabelChange::Unchanged(label) => {
+                    plan.push(Action::Keep, format!("label \"{}\"", label.name))
+                }
+            }
+        }
+
+        plan
+    }
+
+    /// Plan de la commande `labels`, qui crée uniquement les labels manquants.
+    ///
+    /// Les noms de `desired` doivent déjà être normalisés par
+    /// [`RepositoryProvider::label_name`], voir [`plan_labels`].
+    pub fn for_labels(desired: &[Label], existing: &[Label]) -> Self {
+        let mut plan = Plan::default();
+
+        for label in desired {
+            if existing
+                .iter()
+                .any(|e| e.name.to_lowercase() == label.name.to_lowercase())
+            {
+                plan.push(
+                    Action::Keep,
+                    format!("label \"{}\" (already exists)", label.name),
+                );
+            } else {
+                plan.push(Action::Create, format!("label {}", describe_label(label)));
+            }
+        }
+
+        plan
+    }
+
+    /// Plan de la commande `issues`
+    pub fn for_issues(issues: &[IssueCreate], existing: &[RemoteIssue]) -> Self {
+        let mut plan = Plan::default();
+
+        for issue in issues {
+            match existing.iter().find(|e| e.title == issue.title) {
+                Some(remote) => plan.push(
+                    Action::Keep,
+                    format!("issue \"{}\" (already exists #{})", issue.title, remote.id),
+                ),
+                None => plan.push(Action::Create, format!("issue \"{}\"", issue.title)),
+            }
+        }
+
+        plan
+    }
+
+    /// Plan de la commande `setup` : milestones, puis issues, puis liens.
+    ///
+    /// Les APIs ne listent pas les liens : seuls ceux enregistrés dans l'état
+    /// d'un précédent `setup` sont considérés comme existants.
+    pub fn for_project(
+        project: &Project,
+        existing: &ExistingEntities,
+        state: &ProjectState,
+    ) -> Self {
+        let mut plan = Plan::default();
+
+        for milestone in &project.milestones {
+            match existing.milestone(milestone) {
+                Some(_) => plan.push(
+                    Action::Keep,
+                    format!("milestone \"{}\" (already exists)", milestone.name),
+                ),
+                None => plan.push(
+                    Action::Create,
+                    format!(
+                        "milestone \"{}\" (due {})",
+                        milestone.name, milestone.deadline
+                    ),
+                ),
+            }
+        }
+
+        let order = DependencyGraph::new(project).topological_order();
+        for issue in order.into_iter().map(|index| &project.issues[index]) {
+            let milestone = project
+                .milestones
+                .iter()
+                .find(|m| m.version == issue.milestone)
+                .map(|m| m.name.as_str())
+                .unwrap_or(issue.milestone.as_str());
+
+            match existing.issue(issue) {
+                Some(remote) => plan.push(
+                    Action::Keep,
+                    format!("issue \"{}\" (already exists #{})", issue.title, remote.id),
+                ),
+                None => plan.push(
+                    Action::Create,
+                    format!("issue \"{}\" in milestone \"{}\"", issue.title, milestone),
+                ),
+            }
+        }
+
+        for issue in &project.issues {
+            for (to, kind) in issue.relations() {
+                if !project.issues.iter().any(|i| i.title == to) {
+                    continue;
+                }
+                let description = match kind {
+                    LinkKind::BlockedBy => format!("link \"{}\" -> \"{}\"", issue.title, to),
+                    _ => format!("link \"{}\" -> \"{}\" ({})", issue.title, to, kind.name()),
+                };
+                if state.has_link(&issue.title, to, kind) {
+                    plan.push(Action::Keep, format!("{} (already exists)", description));
+                } else {
+                    plan.push(Action::Create, description);
+                }
+            }
+        }
+
+        plan
+    }
+}
+
+fn describe_label(label: &Label) -> String {
+    match &label.description {
+        Some(description) => format!("\"{}\" ({}, {})", label.name, label.color, description),
+        None => format!("\"{}\" ({})", label.name, label.color),
+    }
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "  {} {}", change.action.symbol(), change.description)?;
+        }
+
+        if !self.changes.is_empty() {
+            writeln!(f)?;
+        }
+
+        if self.has_changes() {
+            write!(
+                f,
+                "Plan: {} to create, {} to update, {} to delete, {} unchanged.",
+                self.count(Action::Create),
+                self.count(Action::Update),
+                self.count(Action::Delete),
+                self.count(Action::Keep)
+            )
+        } else {
+            write!(f, "No changes. The repository matches the configuration.")
+        }
+    }
+}
+
+/// Calcule le plan de la commande `labels`, avec les noms tels que le provider les stocke
+pub async fn plan_labels(
+    provider: &dyn RepositoryProvider,
+    desired: &[Label],
+) -> Result<Plan, ProviderError> {
+    let desired = normalize_labels(provider, desired);
+    let existing = provider.list_labels().await?;
+
+    Ok(Plan::for_labels(&desired, &existing))
+}
+
+/// Calcule le plan de `setup` à partir de l'état actuel du repository et de
+/// l'état enregistré par les précédents `setup`
+pub async fn plan_project(
+    provider: &dyn RepositoryProvider,
+    project: &Project,
+    state: &ProjectState,
+) -> Result<Plan, ProviderError> {
+    validate_project(project).map_err(ProviderError::InvalidProject)?;
+    let existing = ExistingEntities::load(provider).await?;
+
+    Ok(Plan::for_project(project, &existing, state))
+}
@@ -0,0 +1,663 @@
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use reqwest::{header, Client, RequestBuilder};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::error::ProviderError;
+use crate::models::common::{
+    Estimate, IssueCreate, IssueFilter, IssueState, Label, LinkKind, Milestone, ProjectIssue,
+    RemoteIssue, RemoteMilestone, RemoteRef, HOURS_PER_DAY,
+};
+use crate::providers::http::HttpClient;
+use crate::providers::ProviderConfig;
+use crate::traits::repository::RepositoryProvider;
+
+const API_VERSION: &str = "7.1";
+// L'API des tags n'existe qu'en preview
+const TAGS_API_VERSION: &str = "7.1-preview.1";
+
+/// Type des work items créés pour les issues (processus Agile)
+const WORK_ITEM_TYPE: &str = "User Story";
+
+/// Durée d'un story point, comme par défaut dans `report schedule` : une
+/// User Story n'a pas d'original estimate, les durées sont converties en points
+const HOURS_PER_POINT: f64 = HOURS_PER_DAY;
+
+// Nombre maximum d'identifiants par requête de lecture groupée
+const BATCH_SIZE: usize = 200;
+
+/// États considérés comme terminés dans les processus Agile, Scrum, CMMI et Basic
+const CLOSED_STATES: [&str; 4] = ["Closed", "Done", "Removed", "Resolved"];
+
+/// Relation "prédécesseur" : le work item cible doit être terminé avant
+const PREDECESSOR: &str = "System.LinkTypes.Dependency-Reverse";
+const SUCCESSOR: &str = "System.LinkTypes.Dependency-Forward";
+const RELATED: &str = "System.LinkTypes.Related";
+/// Relation vers un work item enfant
+const CHILD: &str = "System.LinkTypes.Hierarchy-Forward";
+const PARENT: &str = "System.LinkTypes.Hierarchy-Reverse";
+
+const JSON_PATCH: &str = "application/json-patch+json";
+
+/// Opération JSON Patch, format attendu pour créer ou modifier un work item
+#[derive(Debug, Serialize)]
+struct PatchOperation {
+    op: &'static str,
+    path: String,
+    value: Value,
+}
+
+impl PatchOperation {
+    fn add(path: impl Into<String>, value: impl Into<Value>) -> Self {
+        Self {
+            op: "add",
+            path: path.into(),
+            value: value.into(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct AzureList<T> {
+    value: Vec<T>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AzureNodeAttributes {
+    #[serde(rename = "finishDate")]
+    finish_date: Option<String>,
+}
+
+/// Nœud de classification (itération)
+#[derive(Debug, Deserialize)]
+struct AzureNode {
+    id: i64,
+    name: String,
+    attributes: Option<AzureNodeAttributes>,
+    #[serde(default)]
+    children: Vec<AzureNode>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AzureFields {
+    #[serde(rename = "System.Title")]
+    title: String,
+    #[serde(rename = "System.Description")]
+    description: Option<String>,
+    #[serde(rename = "System.State")]
+    state: String,
+    #[serde(rename = "System.Tags")]
+    tags: Option<String>,
+    #[serde(rename = "System.IterationPath")]
+    iteration_path: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AzureRelation {
+    rel: String,
+    url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct AzureWorkItem {
+    id: i64,
+    fields: AzureFields,
+    #[serde(default)]
+    relations: Option<Vec<AzureRelation>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AzureWorkItemRef {
+    id: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct WiqlResponse {
+    #[serde(rename = "workItems")]
+    work_items: Vec<AzureWorkItemRef>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AzureTag {
+    name: String,
+}
+
+fn parse_state(state: &str) -> IssueState {
+    if CLOSED_STATES.contains(&state) {
+        IssueState::Closed
+    } else {
+        IssueState::Open
+    }
+}
+
+/// Échappe une valeur pour une requête WIQL
+fn quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+fn encode(segment: &str) -> String {
+    utf8_percent_encode(segment, NON_ALPHANUMERIC).to_string()
+}
+
+fn sprint_name(sprint: u32) -> String {
+    format!("Sprint {}", sprint)
+}
+
+impl From<AzureNode> for RemoteMilestone {
+    fn from(node: AzureNode) -> Self {
+        RemoteMilestone {
+            id: node.id,
+            title: node.name,
+            description: None,
+            due_date: node
+                .attributes
+                .and_then(|attributes| attributes.finish_date)
+                .map(|date| date.chars().take(10).collect()),
+            state: IssueState::Open,
+        }
+    }
+}
+
+/// Provider pour Azure DevOps Boards.
+///
+/// Les milestones sont des itérations de premier niveau et les sprints des
+/// itérations enfants (`Projet\Milestone\Sprint N`). Les issues sont des work
+/// items de type `User Story`, les labels des tags, et les dépendances des
+/// relations Predecessor/Successor.
+pub struct AzureDevOpsProvider {
+    client: HttpClient,
+    /// URL de l'organisation (`https://dev.azure.com/organisation`)
+    organization_url: String,
+    project: String,
+    /// Itérations de sprint déjà vérifiées, par milestone
+    sprints: Mutex<HashSet<(String, u32)>>,
+}
+
+impl AzureDevOpsProvider {
+    pub fn new(config: ProviderConfig) -> Result<Self, ProviderError> {
+        let (organization, project) = config.repository.split_once('/').ok_or_else(|| {
+            ProviderError::Config(format!(
+                "Invalid repository, expected organization/project: {}",
+                config.repository
+            ))
+        })?;
+
+        // Les PAT s'utilisent en authentification Basic avec un utilisateur vide
+        let credentials = STANDARD.encode(format!(":{}", config.token));
+        let mut headers = header::HeaderMap::new();
+        headers.insert(
+            header::AUTHORIZATION,
+            header::HeaderValue::from_str(&format!("Basic {}", credentials))
+                .map_err(|e| ProviderError::Config(format!("Invalid token: {}", e)))?,
+        );
+
+        let client = Client::builder()
+            .default_headers(headers)
+            .build()
+            .map_err(|e| ProviderError::Network(e.to_string()))?;
+
+        Ok(Self {
+            client: HttpClient::new(client, config.retry),
+            organization_url: format!("{}/{}", config.api_url, encode(organization)),
+            project: project.to_string(),
+            sprints: Mutex::new(HashSet::new()),
+        })
+    }
+
+    fn project_url(&self, path: &str) -> String {
+        format!(
+            "{}/{}/_apis/{}",
+            self.organization_url,
+            encode(&self.project),
+            path
+        )
+    }
+
+    /// URL d'un nœud d'itération, à partir de son chemin sous la racine
+    fn iteration_url(&self, segments: &[&str]) -> String {
+        let mut url = self.project_url("wit/classificationnodes/Iterations");
+        for segment in segments {
+            url.push('/');
+            url.push_str(&encode(segment));
+        }
+        url
+    }
+
+    fn work_item_url(&self, id: i64) -> String {
+        format!("{}/_apis/wit/workItems/{}", self.organization_url, id)
+    }
+
+    fn web_url(&self, id: i64) -> String {
+        format!(
+            "{}/{}/_workitems/edit/{}",
+            self.organization_url,
+            encode(&self.project),
+            id
+        )
+    }
+
+    /// Chemin d'itération tel qu'attendu par `System.IterationPath`
+    fn iteration_path(&self, segments: &[&str]) -> String {
+        std::iter::once(self.project.as_str())
+            .chain(segments.iter().copied())
+            .collect::<Vec<_>>()
+            .join("\\")
+    }
+
+    fn versioned(request: RequestBuilder) -> RequestBuilder {
+        request.query(&[("api-version", API_VERSION)])
+    }
+
+    fn json_patch(
+        request: RequestBuilder,
+        operations: &[PatchOperation],
+    ) -> Result<RequestBuilder, ProviderError> {
+        Ok(Self::versioned(request)
+            .header(header::CONTENT_TYPE, JSON_PATCH)
+            .body(serde_json::to_vec(operations)?))
+    }
+
+    async fn get_json<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        query: &[(&str, String)],
+    ) -> Result<T, ProviderError> {
+        let response = self
+            .client
+            .send(Self::versioned(self.client.get(url)).query(query))
+            .await?;
+
+        response
+            .json::<T>()
+            .await
+            .map_err(|e| ProviderError::Decode(format!("Failed to parse response: {}", e)))
+    }
+
+    fn remote_issue(&self, work_item: AzureWorkItem) -> RemoteIssue {
+        let fields = work_item.fields;
+
+        RemoteIssue {
+            id: work_item.id,
+            title: fields.title,
+            description: fields.description.unwrap_or_default(),
+            state: parse_state(&fields.state),
+            labels: fields
+                .tags
+                .map(|tags| {
+                    tags.split(';')
+                        .map(str::trim)
+                        .filter(|tag| !tag.is_empty())
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            // Projet\Milestone\Sprint N : le milestone est le deuxième segment
+            milestone: fields
+                .iteration_path
+                .and_then(|path| path.split('\\').nth(1).map(str::to_string)),
+            web_url: self.web_url(work_item.id),
+        }
+    }
+
+    /// Crée l'itération `name` sous `parent` si elle n'existe pas encore
+    async fn ensure_iteration(&self, parent: &[&str], name: &str) -> Result<(), ProviderError> {
+        let mut segments = parent.to_vec();
+        segments.push(name);
+
+        match self
+            .get_json::<AzureNode>(&self.iteration_url(&segments), &[])
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(ProviderError::NotFound(_)) => {
+                self.client
+                    .send(
+                        Self::versioned(self.client.post(self.iteration_url(parent)))
+                            .json(&json!({ "name": name })),
+                    )
+                    .await?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn create_work_item(
+        &self,
+        title: &str,
+        description: &str,
+        tags: &[String],
+        iteration_path: Option<String>,
+        assigned_to: Option<&str>,
+        estimate: Option<Estimate>,
+    ) -> Result<RemoteRef, ProviderError> {
+        let mut operations = vec![
+            PatchOperation::add("/fields/System.Title", title),
+            PatchOperation::add("/fields/System.Description", description),
+            PatchOperation::add("/multilineFieldsFormat/System.Description", "Markdown"),
+        ];
+
+        if !tags.is_empty() {
+            operations.push(PatchOperation::add("/fields/System.Tags", tags.join("; ")));
+        }
+
+        if let Some(iteration_path) = iteration_path {
+            operations.push(PatchOperation::add(
+                "/fields/System.IterationPath",
+                iteration_path,
+            ));
+        }
+
+        if let Some(assigned_to) = assigned_to {
+            operations.push(PatchOperation::add(
+                "/fields/System.AssignedTo",
+                assigned_to,
+            ));
+        }
+
+        let story_points = match estimate {
+            Some(Estimate::Points(points)) => Some(points),
+            Some(Estimate::Hours(hours)) => Some(hours / HOURS_PER_POINT),
+            None => None,
+        };
+        if let Some(points) = story_points {
+            operations.push(PatchOperation::add(
+                "/fields/Microsoft.VSTS.Scheduling.StoryPoints",
+                points,
+            ));
+        }
+
+        let url = self.project_url(&format!("wit/workitems/${}", encode(WORK_ITEM_TYPE)));
+        let response = self
+            .client
+            .send(Self::json_patch(self.client.post(&url), &operations)?)
+            .await?;
+
+        let work_item = response.json::<AzureWorkItemRef>().await.map_err(|e| {
+            ProviderError::Decode(format!("Failed to parse work item response: {}", e))
+        })?;
+
+        Ok(RemoteRef::new(work_item.id).with_web_url(self.web_url(work_item.id)))
+    }
+}
+
+#[async_trait]
+impl RepositoryProvider for AzureDevOpsProvider {
+    async fn create_label(&self, _label: &Label) -> Result<Option<RemoteRef>, ProviderError> {
+        // Azure DevOps crée les tags à leur première utilisation sur un work item
+        Ok(None)
+    }
+
+    async fn update_label(&self, _label: &Label) -> Result<(), ProviderError> {
+        // Jamais appelé par la synchronisation : voir `supports_label_colors`
+        Ok(())
+    }
+
+    fn supports_label_colors(&self) -> bool {
+        // Les tags n'ont ni couleur ni description
+        false
+    }
+
+    async fn delete_label(&self, name: &str) -> Result<(), ProviderError> {
+        let url = self.project_url(&format!("wit/tags/{}", encode(name)));
+
+        self.client
+            .send(
+                self.client
+                    .delete(&url)
+                    .query(&[("api-version", TAGS_API_VERSION)]),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn create_issue(&self, issue: &IssueCreate) -> Result<RemoteRef, ProviderError> {
+        // Un work item n'a qu'une personne assignée, et pas d'échéance pour une User Story
+        if issue.assignees.len() > 1 {
+            return Err(ProviderError::Config(format!(
+                "Azure DevOps work items have a single assignee: {}",
+                issue.assignees.join(", ")
+            )));
+        }
+        if let Some(due_date) = &issue.due_date {
+            return Err(ProviderError::Config(format!(
+                "Azure DevOps user stories have no due date: {}",
+                due_date
+            )));
+        }
+
+        // Le milestone est l'itération de premier niveau du même nom
+        self.create_work_item(
+            &issue.title,
+            &issue.description,
+            &issue.labels,
+            issue
+                .milestone
+                .as_deref()
+                .map(|milestone| self.iteration_path(&[milestone])),
+            issue.assignees.first().map(String::as_str),
+            // Le poids est l'équivalent des story points
+            issue
+                .weight
+                .map(|weight| Estimate::Points(f64::from(weight))),
+        )
+        .await
+    }
+
+    async fn create_milestone(&self, milestone: &Milestone) -> Result<RemoteRef, ProviderError> {
+        let body = json!({
+            "name": milestone.name,
+            "attributes": {
+                "finishDate": format!("{}T00:00:00Z", milestone.deadline),
+            },
+        });
+
+        let response = self
+            .client
+            .send(Self::versioned(self.client.post(self.iteration_url(&[]))).json(&body))
+            .await?;
+
+        let node = response.json::<AzureNode>().await.map_err(|e| {
+            ProviderError::Decode(format!("Failed to parse iteration response: {}", e))
+        })?;
+
+        Ok(RemoteRef::new(node.id))
+    }
+
+    async fn create_issue_link(
+        &self,
+        from_id: i64,
+        to_id: i64,
+        kind: LinkKind,
+    ) -> Result<(), ProviderError> {
+        // Relation de `from_id` vers `to_id` ; Azure DevOps ajoute
+        // automatiquement la relation réciproque sur `to_id`
+        let rel = match kind {
+            LinkKind::BlockedBy => PREDECESSOR,
+            LinkKind::Blocks => SUCCESSOR,
+            LinkKind::RelatesTo => RELATED,
+            LinkKind::Parent => CHILD,
+            LinkKind::Child => PARENT,
+        };
+
+        let work_item: AzureWorkItem = self
+            .get_json(
+                &self.project_url(&format!("wit/workitems/{}", from_id)),
+                &[("$expand", "relations".to_string())],
+            )
+            .await?;
+
+        let to = to_id.to_string();
+        let already_linked = work_item
+            .relations
+            .unwrap_or_default()
+            .iter()
+            .any(|r| r.rel == rel && r.url.rsplit('/').next() == Some(to.as_str()));
+        if already_linked {
+            return Ok(());
+        }
+
+        let operations = [PatchOperation::add(
+            "/relations/-",
+            json!({ "rel": rel, "url": self.work_item_url(to_id) }),
+        )];
+
+        let url = self.project_url(&format!("wit/workitems/{}", from_id));
+        self.client
+            .send(Self::json_patch(self.client.patch(&url), &operations)?)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn list_labels(&self) -> Result<Vec<Label>, ProviderError> {
+        let response = self
+            .client
+            .send(
+                self.client
+                    .get(self.project_url("wit/tags"))
+                    .query(&[("api-version", TAGS_API_VERSION)]),
+            )
+            .await?;
+
+        let tags = response
+            .json::<AzureList<AzureTag>>()
+            .await
+            .map_err(|e| ProviderError::Decode(format!("Failed to parse response: {}", e)))?;
+
+        Ok(tags
+            .value
+            .into_iter()
+            .map(|tag| Label {
+                name: tag.name,
+                color: String::new(),
+                description: None,
+            })
+            .collect())
+    }
+
+    async fn list_milestones(&self) -> Result<Vec<RemoteMilestone>, ProviderError> {
+        let root: AzureNode = self
+            .get_json(&self.iteration_url(&[]), &[("$depth", "1".to_string())])
+            .await?;
+
+        Ok(root
+            .children
+            .into_iter()
+            .map(RemoteMilestone::from)
+            .collect())
+    }
+
+    async fn list_issues(&self, filter: &IssueFilter) -> Result<Vec<RemoteIssue>, ProviderError> {
+        let mut clauses = vec![
+            "[System.TeamProject] = @project".to_string(),
+            format!("[System.WorkItemType] = {}", quote(WORK_ITEM_TYPE)),
+        ];
+
+        let closed = CLOSED_STATES.map(quote).join(", ");
+        match filter.state {
+            Some(IssueState::Open) => clauses.push(format!("[System.State] NOT IN ({})", closed)),
+            Some(IssueState::Closed) => clauses.push(format!("[System.State] IN ({})", closed)),
+            None => {}
+        }
+
+        for label in &filter.labels {
+            clauses.push(format!("[System.Tags] CONTAINS {}", quote(label)));
+        }
+
+        if let Some(milestone) = &filter.milestone {
+            clauses.push(format!(
+                "[System.IterationPath] UNDER {}",
+                quote(&self.iteration_path(&[milestone]))
+            ));
+        }
+
+        let query = format!(
+            "SELECT [System.Id] FROM WorkItems WHERE {} ORDER BY [System.Id]",
+            clauses.join(" AND ")
+        );
+
+        let response = self
+            .client
+            .send(
+                Self::versioned(self.client.post(self.project_url("wit/wiql")))
+                    .json(&json!({ "query": query })),
+            )
+            .await?;
+
+        let result = response
+            .json::<WiqlResponse>()
+            .await
+            .map_err(|e| ProviderError::Decode(format!("Failed to parse WIQL response: {}", e)))?;
+
+        // WIQL ne retourne que les identifiants : les work items sont lus par lots
+        let mut issues = Vec::with_capacity(result.work_items.len());
+        for batch in result.work_items.chunks(BATCH_SIZE) {
+            let ids: Vec<String> = batch.iter().map(|item| item.id.to_string()).collect();
+            let work_items: AzureList<AzureWorkItem> = self
+                .get_json(
+                    &self.project_url("wit/workitems"),
+                    &[("ids", ids.join(","))],
+                )
+                .await?;
+
+            issues.extend(
+                work_items
+                    .value
+                    .into_iter()
+                    .map(|work_item| self.remote_issue(work_item)),
+            );
+        }
+
+        Ok(issues)
+    }
+
+    async fn get_issue(&self, id: i64) -> Result<RemoteIssue, ProviderError> {
+        let work_item: AzureWorkItem = self
+            .get_json(&self.project_url(&format!("wit/workitems/{}", id)), &[])
+            .await?;
+
+        Ok(self.remote_issue(work_item))
+    }
+
+    async fn create_project_issue(
+        &self,
+        issue: &ProjectIssue,
+        milestone: &Milestone,
+        _milestone_id: i64,
+    ) -> Result<RemoteRef, ProviderError> {
+        // Le work item est placé dans l'itération de son sprint, sous le milestone
+        let sprint = sprint_name(issue.sprint);
+        let key = (milestone.name.clone(), issue.sprint);
+        let known = self
+            .sprints
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .contains(&key);
+        if !known {
+            self.ensure_iteration(&[&milestone.name], &sprint).await?;
+            self.sprints
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .insert(key);
+        }
+
+        self.create_work_item(
+            &issue.title,
+            &issue.to_markdown(),
+            &issue.labels,
+            Some(self.iteration_path(&[&milestone.name, &sprint])),
+            None,
+            issue.parsed_estimate(),
+        )
+        .await
+    }
+}
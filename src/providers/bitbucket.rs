@@ -0,0 +1,552 @@
+use async_trait::async_trait;
+use futures::TryStreamExt;
+use reqwest::{header, Client};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ProviderError;
+use crate::models::common::{
+    IssueCreate, IssueFilter, IssueState, Label, LinkKind, Milestone, ProjectIssue, RemoteIssue,
+    RemoteMilestone, RemoteRef,
+};
+use crate::providers::http::HttpClient;
+use crate::providers::pagination::{paginate, Pagination};
+use crate::providers::ProviderConfig;
+use crate::traits::repository::RepositoryProvider;
+
+// Taille de page maximale acceptée par l'API des issues
+const PAGE_LEN: &str = "50";
+
+/// Types d'issues prédéfinis de Bitbucket, exposés comme labels
+const KINDS: [&str; 4] = ["bug", "enhancement", "proposal", "task"];
+
+#[derive(Debug, Serialize)]
+struct BitbucketContent<'a> {
+    raw: &'a str,
+    markup: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct BitbucketNameRef<'a> {
+    name: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct BitbucketIssue<'a> {
+    title: &'a str,
+    content: BitbucketContent<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    kind: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    component: Option<BitbucketNameRef<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    milestone: Option<BitbucketNameRef<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<BitbucketNameRef<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct BitbucketComment<'a> {
+    content: BitbucketContent<'a>,
+}
+
+/// Component, milestone ou version : Bitbucket ne conserve qu'un nom
+#[derive(Debug, Deserialize)]
+struct BitbucketNamed {
+    id: i64,
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketNameDetails {
+    name: String,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct BitbucketContentDetails {
+    raw: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketHref {
+    href: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketLinks {
+    html: BitbucketHref,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketIssueDetails {
+    id: i64,
+    title: String,
+    #[serde(default)]
+    content: Option<BitbucketContentDetails>,
+    state: String,
+    kind: Option<String>,
+    component: Option<BitbucketNameDetails>,
+    milestone: Option<BitbucketNameDetails>,
+    version: Option<BitbucketNameDetails>,
+    links: BitbucketLinks,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketIssueRef {
+    id: i64,
+    links: Option<BitbucketLinks>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketCommentDetails {
+    #[serde(default)]
+    content: Option<BitbucketContentDetails>,
+}
+
+// Bitbucket distingue plusieurs états de résolution : seuls "new", "open" et
+// "on hold" correspondent à une issue ouverte
+fn parse_state(state: &str) -> IssueState {
+    match state {
+        "new" | "open" | "on hold" => IssueState::Open,
+        _ => IssueState::Closed,
+    }
+}
+
+/// Valeur d'un label sans son éventuel préfixe (`type::bug` -> `bug`)
+fn label_value(name: &str) -> &str {
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+fn kind_for(labels: &[String]) -> Option<&'static str> {
+    labels.iter().find_map(|label| {
+        KINDS
+            .into_iter()
+            .find(|kind| kind.eq_ignore_ascii_case(label_value(label)))
+    })
+}
+
+/// Échappe une valeur pour une requête BBQL (`q=`)
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+impl From<BitbucketIssueDetails> for RemoteIssue {
+    fn from(issue: BitbucketIssueDetails) -> Self {
+        let labels = issue
+            .kind
+            .into_iter()
+            .chain(issue.component.map(|component| component.name))
+            .collect();
+
+        RemoteIssue {
+            id: issue.id,
+            title: issue.title,
+            description: issue.content.and_then(|c| c.raw).unwrap_or_default(),
+            state: parse_state(&issue.state),
+            labels,
+            milestone: issue.milestone.or(issue.version).map(|m| m.name),
+            web_url: issue.links.html.href,
+        }
+    }
+}
+
+impl From<BitbucketNamed> for RemoteMilestone {
+    fn from(milestone: BitbucketNamed) -> Self {
+        RemoteMilestone {
+            id: milestone.id,
+            title: milestone.name,
+            description: None,
+            due_date: None,
+            state: IssueState::Open,
+        }
+    }
+}
+
+/// Provider pour l'issue tracker de Bitbucket Cloud (API 2.0).
+///
+/// Bitbucket n'a ni labels ni milestones au sens de GitHub : les types d'issue
+/// (`bug`, `task`...) et les components tiennent lieu de labels, les milestones
+/// et versions de milestones. L'API ne permet pas de créer ces entités, elles
+/// doivent exister dans les paramètres du repository.
+pub struct BitbucketProvider {
+    client: HttpClient,
+    api_url: String,
+    repo: String,
+}
+
+impl BitbucketProvider {
+    pub fn new(config: ProviderConfig) -> Result<Self, ProviderError> {
+        let mut headers = header::HeaderMap::new();
+        headers.insert(
+            header::AUTHORIZATION,
+            header::HeaderValue::from_str(&format!("Bearer {}", config.token))
+                .map_err(|e| ProviderError::Config(format!("Invalid token: {}", e)))?,
+        );
+
+        if config.repository.split('/').count() != 2 {
+            return Err(ProviderError::Config(format!(
+                "Invalid repository, expected workspace/repo_slug: {}",
+                config.repository
+            )));
+        }
+
+        let client = Client::builder()
+            .default_headers(headers)
+            .build()
+            .map_err(|e| ProviderError::Network(e.to_string()))?;
+
+        Ok(Self {
+            client: HttpClient::new(client, config.retry),
+            api_url: config.api_url,
+            repo: config.repository,
+        })
+    }
+
+    fn repo_url(&self, path: &str) -> String {
+        format!("{}/repositories/{}/{}", self.api_url, self.repo, path)
+    }
+
+    async fn get_json<T: DeserializeOwned>(&self, url: &str) -> Result<T, ProviderError> {
+        let response = self.client.send(self.client.get(url)).await?;
+
+        response
+            .json::<T>()
+            .await
+            .map_err(|e| ProviderError::Decode(format!("Failed to parse response: {}", e)))
+    }
+
+    async fn list_named(&self, path: &str) -> Result<Vec<BitbucketNamed>, ProviderError> {
+        paginate(
+            self.client.clone(),
+            self.repo_url(path),
+            vec![("pagelen", PAGE_LEN.to_string())],
+            Pagination::NextField,
+        )
+        .try_collect()
+        .await
+    }
+
+    /// Crée une issue en associant ses labels au type et au component
+    /// correspondants ; les autres labels sont ignorés.
+    async fn create_issue_with(
+        &self,
+        title: &str,
+        body: &str,
+        labels: &[String],
+        milestone: Option<&str>,
+        version: Option<&str>,
+    ) -> Result<RemoteRef, ProviderError> {
+        let components = if labels.is_empty() {
+            Vec::new()
+        } else {
+            self.list_named("components").await?
+        };
+
+        let bitbucket_issue = BitbucketIssue {
+            title,
+            content: BitbucketContent {
+                raw: body,
+                markup: "markdown",
+            },
+            kind: kind_for(labels),
+            component: component_for(labels, &components).map(|name| BitbucketNameRef { name }),
+            milestone: milestone.map(|name| BitbucketNameRef { name }),
+            version: version.map(|name| BitbucketNameRef { name }),
+        };
+
+        let response = self
+            .client
+            .send(
+                self.client
+                    .post(self.repo_url("issues"))
+                    .json(&bitbucket_issue),
+            )
+            .await?;
+
+        let issue = response
+            .json::<BitbucketIssueRef>()
+            .await
+            .map_err(|e| ProviderError::Decode(format!("Failed to parse issue response: {}", e)))?;
+
+        Ok(RemoteRef {
+            id: issue.id,
+            global_id: None,
+            web_url: issue.links.map(|links| links.html.href),
+            node_id: None,
+        })
+    }
+}
+
+/// Nom du premier component correspondant à l'un des labels
+fn component_for<'a>(labels: &[String], components: &'a [BitbucketNamed]) -> Option<&'a str> {
+    labels.iter().find_map(|label| {
+        components
+            .iter()
+            .find(|component| {
+                component.name.eq_ignore_ascii_case(label)
+                    || component.name.eq_ignore_ascii_case(label_value(label))
+            })
+            .map(|component| component.name.as_str())
+    })
+}
+
+fn find_named<'a>(entities: &'a [BitbucketNamed], name: &str) -> Option<&'a BitbucketNamed> {
+    entities
+        .iter()
+        .find(|entity| entity.name.eq_ignore_ascii_case(name))
+}
+
+#[async_trait]
+impl RepositoryProvider for BitbucketProvider {
+    async fn create_label(&self, label: &Label) -> Result<Option<RemoteRef>, ProviderError> {
+        // Rien à créer si le label correspond déjà à un type ou à un component
+        let existing = self.list_labels().await?;
+        if existing
+            .iter()
+            .any(|l| l.name.eq_ignore_ascii_case(label_value(&label.name)))
+        {
+            return Ok(None);
+        }
+
+        Err(ProviderError::Config(format!(
+            "Bitbucket cannot create components through its API, add it in the repository settings: {}",
+            label.name
+        )))
+    }
+
+    async fn update_label(&self, _label: &Label) -> Result<(), ProviderError> {
+        // Jamais appelé par la synchronisation : voir `supports_label_colors`
+        Ok(())
+    }
+
+    fn supports_label_colors(&self) -> bool {
+        // Les types et components Bitbucket n'ont ni couleur ni description
+        false
+    }
+
+    fn label_name(&self, name: &str) -> String {
+        label_value(name).to_string()
+    }
+
+    fn supports_label_deletion(&self) -> bool {
+        false
+    }
+
+    async fn delete_label(&self, name: &str) -> Result<(), ProviderError> {
+        Err(ProviderError::Config(format!(
+            "Bitbucket cannot delete issue kinds or components through its API: {}",
+            name
+        )))
+    }
+
+    async fn create_issue(&self, issue: &IssueCreate) -> Result<RemoteRef, ProviderError> {
+        // L'API 2.0 n'assigne une issue que par identifiant de compte et ne
+        // connaît ni échéance ni poids
+        if !issue.assignees.is_empty() {
+            return Err(ProviderError::Config(format!(
+                "Bitbucket cannot assign issues by username: {}",
+                issue.assignees.join(", ")
+            )));
+        }
+        if let Some(due_date) = &issue.due_date {
+            return Err(ProviderError::Config(format!(
+                "Bitbucket issues have no due date: {}",
+                due_date
+            )));
+        }
+        if let Some(weight) = issue.weight {
+            return Err(ProviderError::Config(format!(
+                "Bitbucket issues have no weight: {}",
+                weight
+            )));
+        }
+
+        // Le milestone est le milestone du même nom, ou à défaut la version
+        let (milestone, version) = match &issue.milestone {
+            Some(title) => {
+                if let Some(existing) = find_named(&self.list_named("milestones").await?, title) {
+                    (Some(existing.name.clone()), None)
+                } else if let Some(existing) =
+                    find_named(&self.list_named("versions").await?, title)
+                {
+                    (None, Some(existing.name.clone()))
+                } else {
+                    return Err(ProviderError::Config(format!(
+                        "Milestone not found: {}",
+                        title
+                    )));
+                }
+            }
+            None => (None, None),
+        };
+
+        self.create_issue_with(
+            &issue.title,
+            &issue.description,
+            &issue.labels,
+            milestone.as_deref(),
+            version.as_deref(),
+        )
+        .await
+    }
+
+    async fn create_milestone(&self, milestone: &Milestone) -> Result<RemoteRef, ProviderError> {
+        // L'API 2.0 ne permet pas de créer de milestone : on réutilise le
+        // milestone du même nom, ou à défaut la version correspondante
+        if let Some(existing) = find_named(&self.list_named("milestones").await?, &milestone.name) {
+            return Ok(RemoteRef::new(existing.id));
+        }
+
+        if let Some(existing) = find_named(&self.list_named("versions").await?, &milestone.version)
+        {
+            return Ok(RemoteRef::new(existing.id));
+        }
+
+        Err(ProviderError::Config(format!(
+            "Bitbucket cannot create milestones through its API, add milestone \"{}\" or version \"{}\" in the repository settings",
+            milestone.name, milestone.version
+        )))
+    }
+
+    async fn create_issue_link(
+        &self,
+        from_id: i64,
+        to_id: i64,
+        kind: LinkKind,
+    ) -> Result<(), ProviderError> {
+        // Pas de liens natifs entre issues : le lien est noté en commentaire
+        let url = self.repo_url(&format!("issues/{}/comments", from_id));
+        let body = kind.describe(to_id);
+
+        let comments: Vec<BitbucketCommentDetails> = paginate(
+            self.client.clone(),
+            url.clone(),
+            vec![("pagelen", PAGE_LEN.to_string())],
+            Pagination::NextField,
+        )
+        .try_collect()
+        .await?;
+
+        if comments
+            .iter()
+            .filter_map(|comment| comment.content.as_ref()?.raw.as_deref())
+            .any(|raw| raw.trim() == body)
+        {
+            return Ok(());
+        }
+
+        let comment = BitbucketComment {
+            content: BitbucketContent {
+                raw: &body,
+                markup: "markdown",
+            },
+        };
+
+        self.client
+            .send(self.client.post(&url).json(&comment))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn list_labels(&self) -> Result<Vec<Label>, ProviderError> {
+        let components = self.list_named("components").await?;
+
+        Ok(KINDS
+            .iter()
+            .map(|kind| kind.to_string())
+            .chain(components.into_iter().map(|component| component.name))
+            .map(|name| Label {
+                name,
+                color: String::new(),
+                description: None,
+            })
+            .collect())
+    }
+
+    async fn list_milestones(&self) -> Result<Vec<RemoteMilestone>, ProviderError> {
+        let mut milestones = self.list_named("milestones").await?;
+
+        // Les versions sans milestone du même nom sont aussi proposées
+        for version in self.list_named("versions").await? {
+            if find_named(&milestones, &version.name).is_none() {
+                milestones.push(version);
+            }
+        }
+
+        Ok(milestones.into_iter().map(RemoteMilestone::from).collect())
+    }
+
+    async fn list_issues(&self, filter: &IssueFilter) -> Result<Vec<RemoteIssue>, ProviderError> {
+        let mut clauses = Vec::new();
+
+        match filter.state {
+            Some(IssueState::Open) => {
+                clauses.push(r#"(state="new" OR state="open" OR state="on hold")"#.to_string())
+            }
+            Some(IssueState::Closed) => {
+                clauses.push(r#"(state!="new" AND state!="open" AND state!="on hold")"#.to_string())
+            }
+            None => {}
+        }
+
+        for label in &filter.labels {
+            clauses.push(format!(
+                "(kind={} OR component.name={})",
+                quote(label_value(label)),
+                quote(label)
+            ));
+        }
+
+        if let Some(milestone) = &filter.milestone {
+            clauses.push(format!(
+                "(milestone.name={} OR version.name={})",
+                quote(milestone),
+                quote(milestone)
+            ));
+        }
+
+        let mut query = vec![("pagelen", PAGE_LEN.to_string())];
+        if !clauses.is_empty() {
+            query.push(("q", clauses.join(" AND ")));
+        }
+
+        paginate::<BitbucketIssueDetails>(
+            self.client.clone(),
+            self.repo_url("issues"),
+            query,
+            Pagination::NextField,
+        )
+        .map_ok(RemoteIssue::from)
+        .try_collect()
+        .await
+    }
+
+    async fn get_issue(&self, id: i64) -> Result<RemoteIssue, ProviderError> {
+        let issue: BitbucketIssueDetails = self
+            .get_json(&self.repo_url(&format!("issues/{}", id)))
+            .await?;
+
+        Ok(issue.into())
+    }
+
+    async fn create_project_issue(
+        &self,
+        issue: &ProjectIssue,
+        milestone: &Milestone,
+        _milestone_id: i64,
+    ) -> Result<RemoteRef, ProviderError> {
+        // Bitbucket référence milestones et versions par leur nom
+        let remote_milestones = self.list_named("milestones").await?;
+        let remote_versions = self.list_named("versions").await?;
+
+        self.create_issue_with(
+            &issue.title,
+            &issue.to_markdown(),
+            &issue.labels,
+            find_named(&remote_milestones, &milestone.name).map(|m| m.name.as_str()),
+            find_named(&remote_versions, &milestone.version).map(|v| v.name.as_str()),
+        )
+        .await
+    }
+}
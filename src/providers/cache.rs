@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+
+use tokio::sync::Mutex;
+
+use crate::error::ProviderError;
+use crate::traits::repository::RepositoryProvider;
+
+/// Identifiants des milestones par titre, listés à la première recherche puis
+/// tenus à jour à chaque création : une exécution ne liste les milestones qu'une fois
+#[derive(Debug, Default)]
+pub(crate) struct MilestoneCache(Mutex<Option<HashMap<String, i64>>>);
+
+impl MilestoneCache {
+    /// Identifiant du milestone portant ce titre, `None` s'il n'existe pas
+    pub(crate) async fn id(
+        &self,
+        provider: &dyn RepositoryProvider,
+        title: &str,
+    ) -> Result<Option<i64>, ProviderError> {
+        let mut milestones = self.0.lock().await;
+
+        let milestones = match &mut *milestones {
+            Some(milestones) => milestones,
+            None => milestones.insert(
+                provider
+                    .list_milestones()
+                    .await?
+                    .into_iter()
+                    .map(|milestone| (milestone.title, milestone.id))
+                    .collect(),
+            ),
+        };
+
+        Ok(milestones.get(title).copied())
+    }
+
+    /// Identifiant du milestone portant ce titre, qui doit exister
+    pub(crate) async fn require(
+        &self,
+        provider: &dyn RepositoryProvider,
+        title: &str,
+    ) -> Result<i64, ProviderError> {
+        self.id(provider, title)
+            .await?
+            .ok_or_else(|| ProviderError::Config(format!("Milestone not found: {}", title)))
+    }
+
+    /// Enregistre un milestone créé, si les milestones ont déjà été listés
+    pub(crate) async fn insert(&self, title: &str, id: i64) {
+        if let Some(milestones) = self.0.lock().await.as_mut() {
+            milestones.insert(title.to_string(), id);
+        }
+    }
+}
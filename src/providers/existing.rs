@@ -0,0 +1,66 @@
+use crate::error::ProviderError;
+use crate::models::common::{IssueFilter, Milestone, ProjectIssue, RemoteIssue, RemoteMilestone};
+use crate::state::ProjectState;
+use crate::traits::repository::RepositoryProvider;
+
+/// Milestones et issues déjà présents dans le repository, utilisés pour
+/// rendre `setup` rejouable sans créer de doublons
+#[derive(Debug, Default, Clone)]
+pub struct ExistingEntities {
+    pub milestones: Vec<RemoteMilestone>,
+    pub issues: Vec<RemoteIssue>,
+}
+
+impl ExistingEntities {
+    pub async fn load(provider: &dyn RepositoryProvider) -> Result<Self, ProviderError> {
+        Ok(Self {
+            milestones: provider.list_milestones().await?,
+            issues: provider.list_issues(&IssueFilter::default()).await?,
+        })
+    }
+
+    /// Existant à prendre en compte pour `setup`. Lors d'une reprise, le checkpoint
+    /// fait foi et le repository n'est pas relu.
+    pub async fn for_setup(
+        provider: &dyn RepositoryProvider,
+        state: &ProjectState,
+    ) -> Result<Self, ProviderError> {
+        if state.is_resuming() {
+            return Ok(Self::default());
+        }
+
+        Self::load(provider).await
+    }
+
+    /// Milestone distant portant le même titre
+    pub fn milestone(&self, milestone: &Milestone) -> Option<&RemoteMilestone> {
+        self.milestones.iter().find(|m| m.title == milestone.name)
+    }
+
+    /// Issue distante portant le marqueur de l'issue, ou à défaut le même titre
+    pub fn issue(&self, issue: &ProjectIssue) -> Option<&RemoteIssue> {
+        let marker = issue.marker();
+
+        self.issues
+            .iter()
+            .find(|i| i.description.contains(&marker))
+            .or_else(|| self.issues.iter().find(|i| i.title == issue.title))
+    }
+
+    /// Identifiant d'un milestone déjà créé : d'abord celui enregistré dans l'état
+    /// s'il existe toujours, sinon un milestone distant de même titre
+    pub fn milestone_id(&self, milestone: &Milestone, state: &ProjectState) -> Option<i64> {
+        state
+            .milestone_id(&milestone.version)
+            .filter(|id| state.is_resuming() || self.milestones.iter().any(|m| m.id == *id))
+            .or_else(|| self.milestone(milestone).map(|m| m.id))
+    }
+
+    /// Identifiant d'une issue déjà créée, résolu comme [`Self::milestone_id`]
+    pub fn issue_id(&self, issue: &ProjectIssue, state: &ProjectState) -> Option<i64> {
+        state
+            .issue_id(&issue.title)
+            .filter(|id| state.is_resuming() || self.issues.iter().any(|i| i.id == *id))
+            .or_else(|| self.issue(issue).map(|i| i.id))
+    }
+}
@@ -0,0 +1,515 @@
+use async_trait::async_trait;
+use futures::TryStreamExt;
+use reqwest::{header, Client};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ProviderError;
+use crate::models::common::{
+    IssueCreate, IssueFilter, IssueState, Label, LinkKind, Milestone, RemoteIssue, RemoteMilestone,
+    RemoteRef,
+};
+use crate::providers::cache::MilestoneCache;
+use crate::providers::http::HttpClient;
+use crate::providers::pagination::{paginate, Pagination};
+use crate::providers::ProviderConfig;
+use crate::traits::repository::RepositoryProvider;
+
+// Gitea plafonne la taille des pages à 50 par défaut
+const PAGE_LIMIT: &str = "50";
+
+#[derive(Debug, Serialize)]
+struct GiteaLabel<'a> {
+    name: &'a str,
+    color: &'a str,
+    description: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct GiteaMilestone<'a> {
+    title: &'a str,
+    description: &'a str,
+    due_on: String,
+}
+
+#[derive(Debug, Serialize)]
+struct GiteaIssue<'a> {
+    title: &'a str,
+    body: &'a str,
+    // Gitea attend les identifiants des labels, pas leurs noms
+    labels: Vec<i64>,
+    milestone: Option<i64>,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    assignees: &'a [String],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    due_date: Option<String>,
+}
+
+/// Référence vers une issue, utilisée par l'API des dépendances
+#[derive(Debug, Serialize)]
+struct GiteaIssueMeta<'a> {
+    owner: &'a str,
+    repo: &'a str,
+    index: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GiteaComment {
+    body: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaLabelDetails {
+    id: i64,
+    name: String,
+    color: String,
+    description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaMilestoneDetails {
+    id: i64,
+    title: String,
+    description: Option<String>,
+    due_on: Option<String>,
+    state: IssueState,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaMilestoneRef {
+    title: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaLabelRef {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaIssueDetails {
+    number: i64,
+    title: String,
+    body: Option<String>,
+    state: IssueState,
+    labels: Vec<GiteaLabelRef>,
+    milestone: Option<GiteaMilestoneRef>,
+    html_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaIssueRef {
+    number: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaCreatedIssue {
+    id: i64,
+    number: i64,
+    html_url: String,
+}
+
+impl From<GiteaLabelDetails> for Label {
+    fn from(label: GiteaLabelDetails) -> Self {
+        Label {
+            name: label.name,
+            // Les anciennes versions de Gitea retournent la couleur sans #
+            color: format!("#{}", label.color.trim_start_matches('#')),
+            description: label.description.filter(|d| !d.is_empty()),
+        }
+    }
+}
+
+impl From<GiteaMilestoneDetails> for RemoteMilestone {
+    fn from(milestone: GiteaMilestoneDetails) -> Self {
+        RemoteMilestone {
+            id: milestone.id,
+            title: milestone.title,
+            description: milestone.description,
+            due_date: milestone
+                .due_on
+                .map(|due_on| due_on.chars().take(10).collect()),
+            state: milestone.state,
+        }
+    }
+}
+
+impl From<GiteaIssueDetails> for RemoteIssue {
+    fn from(issue: GiteaIssueDetails) -> Self {
+        RemoteIssue {
+            id: issue.number,
+            title: issue.title,
+            description: issue.body.unwrap_or_default(),
+            state: issue.state,
+            labels: issue.labels.into_iter().map(|label| label.name).collect(),
+            milestone: issue.milestone.map(|milestone| milestone.title),
+            web_url: issue.html_url,
+        }
+    }
+}
+
+/// Provider pour Gitea et Forgejo (API `/api/v1`)
+pub struct GiteaProvider {
+    client: HttpClient,
+    api_url: String,
+    repo: String,
+    /// Identifiants des milestones par titre, chargés à la première recherche
+    milestones: MilestoneCache,
+}
+
+impl GiteaProvider {
+    pub fn new(config: ProviderConfig) -> Result<Self, ProviderError> {
+        let mut headers = header::HeaderMap::new();
+        headers.insert(
+            header::AUTHORIZATION,
+            header::HeaderValue::from_str(&format!("token {}", config.token))
+                .map_err(|e| ProviderError::Config(format!("Invalid token: {}", e)))?,
+        );
+
+        if config.repository.split('/').count() != 2 {
+            return Err(ProviderError::Config(format!(
+                "Invalid repository, expected owner/repo: {}",
+                config.repository
+            )));
+        }
+
+        let client = Client::builder()
+            .default_headers(headers)
+            .build()
+            .map_err(|e| ProviderError::Network(e.to_string()))?;
+
+        Ok(Self {
+            client: HttpClient::new(client, config.retry),
+            api_url: config.api_url,
+            repo: config.repository,
+            milestones: MilestoneCache::default(),
+        })
+    }
+
+    fn repo_url(&self, path: &str) -> String {
+        format!("{}/repos/{}/{}", self.api_url, self.repo, path)
+    }
+
+    fn owner_and_repo(&self) -> (&str, &str) {
+        self.repo.split_once('/').unwrap_or((&self.repo, ""))
+    }
+
+    fn format_date(due_on: &str) -> String {
+        format!("{}T00:00:00Z", due_on)
+    }
+
+    async fn get_json<T: DeserializeOwned>(&self, url: &str) -> Result<T, ProviderError> {
+        let response = self.client.send(self.client.get(url)).await?;
+
+        response
+            .json::<T>()
+            .await
+            .map_err(|e| ProviderError::Decode(format!("Failed to parse response: {}", e)))
+    }
+
+    async fn label_details(&self) -> Result<Vec<GiteaLabelDetails>, ProviderError> {
+        self.list_all(self.repo_url("labels")).await
+    }
+
+    async fn label_id(&self, name: &str) -> Result<i64, ProviderError> {
+        self.label_details()
+            .await?
+            .into_iter()
+            .find(|label| label.name.eq_ignore_ascii_case(name))
+            .map(|label| label.id)
+            .ok_or_else(|| ProviderError::Config(format!("Label not found: {}", name)))
+    }
+
+    /// Résout les noms de labels en identifiants ; un label absent du repository
+    /// est une erreur plutôt qu'un label silencieusement perdu
+    async fn label_ids(&self, names: &[String]) -> Result<Vec<i64>, ProviderError> {
+        if names.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let labels = self.label_details().await?;
+
+        names
+            .iter()
+            .map(|name| {
+                labels
+                    .iter()
+                    .find(|label| label.name.eq_ignore_ascii_case(name))
+                    .map(|label| label.id)
+                    .ok_or_else(|| ProviderError::Config(format!("Label not found: {}", name)))
+            })
+            .collect()
+    }
+
+    /// Tous les éléments d'une liste paginée du repository
+    async fn list_all<T: DeserializeOwned + Send + 'static>(
+        &self,
+        url: String,
+    ) -> Result<Vec<T>, ProviderError> {
+        paginate(
+            self.client.clone(),
+            url,
+            vec![("limit", PAGE_LIMIT.to_string())],
+            Pagination::LinkHeader,
+        )
+        .try_collect()
+        .await
+    }
+
+    /// Ajoute `blocking_id` aux dépendances de `issue_id`
+    async fn add_dependency(&self, issue_id: i64, blocking_id: i64) -> Result<(), ProviderError> {
+        let url = self.repo_url(&format!("issues/{}/dependencies", issue_id));
+
+        let dependencies: Vec<GiteaIssueRef> = self.list_all(url.clone()).await?;
+        if dependencies.iter().any(|issue| issue.number == blocking_id) {
+            return Ok(());
+        }
+
+        let (owner, repo) = self.owner_and_repo();
+        let meta = GiteaIssueMeta {
+            owner,
+            repo,
+            index: blocking_id,
+        };
+
+        self.client.send(self.client.post(&url).json(&meta)).await?;
+
+        Ok(())
+    }
+
+    /// Note le lien dans un commentaire, s'il n'y figure pas déjà
+    async fn comment_link(
+        &self,
+        from_id: i64,
+        to_id: i64,
+        kind: LinkKind,
+    ) -> Result<(), ProviderError> {
+        let url = self.repo_url(&format!("issues/{}/comments", from_id));
+        let body = kind.describe(to_id);
+
+        let comments: Vec<GiteaComment> = self.list_all(url.clone()).await?;
+        if comments.iter().any(|comment| comment.body.trim() == body) {
+            return Ok(());
+        }
+
+        self.client
+            .send(self.client.post(&url).json(&GiteaComment { body }))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Identifiant du milestone portant ce titre
+    async fn milestone_id(&self, title: &str) -> Result<i64, ProviderError> {
+        self.milestones.require(self, title).await
+    }
+
+    async fn post_issue(&self, issue: &IssueCreate) -> Result<RemoteRef, ProviderError> {
+        if let Some(weight) = issue.weight {
+            return Err(ProviderError::Config(format!(
+                "Gitea issues have no weight: {}",
+                weight
+            )));
+        }
+
+        let milestone = match (issue.milestone_id, &issue.milestone) {
+            (Some(id), _) => Some(id),
+            (None, Some(title)) => Some(self.milestone_id(title).await?),
+            (None, None) => None,
+        };
+
+        // Les personnes assignées sont désignées par leur nom d'utilisateur
+        let gitea_issue = GiteaIssue {
+            title: &issue.title,
+            body: &issue.description,
+            labels: self.label_ids(&issue.labels).await?,
+            milestone,
+            assignees: &issue.assignees,
+            due_date: issue.due_date.as_deref().map(Self::format_date),
+        };
+
+        let response = self
+            .client
+            .send(self.client.post(self.repo_url("issues")).json(&gitea_issue))
+            .await?;
+
+        let issue = response
+            .json::<GiteaCreatedIssue>()
+            .await
+            .map_err(|e| ProviderError::Decode(format!("Failed to parse issue response: {}", e)))?;
+
+        Ok(RemoteRef {
+            id: issue.number,
+            global_id: Some(issue.id),
+            web_url: Some(issue.html_url),
+            node_id: None,
+        })
+    }
+}
+
+#[async_trait]
+impl RepositoryProvider for GiteaProvider {
+    async fn create_label(&self, label: &Label) -> Result<Option<RemoteRef>, ProviderError> {
+        let gitea_label = GiteaLabel {
+            name: &label.name,
+            color: &label.color,
+            description: label.description.as_deref().unwrap_or_default(),
+        };
+
+        let response = self
+            .client
+            .send(self.client.post(self.repo_url("labels")).json(&gitea_label))
+            .await?;
+
+        let label = response
+            .json::<GiteaLabelDetails>()
+            .await
+            .map_err(|e| ProviderError::Decode(format!("Failed to parse label response: {}", e)))?;
+
+        Ok(Some(RemoteRef::new(label.id)))
+    }
+
+    async fn update_label(&self, label: &Label) -> Result<(), ProviderError> {
+        let id = self.label_id(&label.name).await?;
+
+        let gitea_label = GiteaLabel {
+            name: &label.name,
+            color: &label.color,
+            description: label.description.as_deref().unwrap_or_default(),
+        };
+
+        self.client
+            .send(
+                self.client
+                    .patch(self.repo_url(&format!("labels/{}", id)))
+                    .json(&gitea_label),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete_label(&self, name: &str) -> Result<(), ProviderError> {
+        let id = self.label_id(name).await?;
+
+        self.client
+            .send(self.client.delete(self.repo_url(&format!("labels/{}", id))))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn create_issue(&self, issue: &IssueCreate) -> Result<RemoteRef, ProviderError> {
+        self.post_issue(issue).await
+    }
+
+    async fn create_milestone(&self, milestone: &Milestone) -> Result<RemoteRef, ProviderError> {
+        let gitea_milestone = GiteaMilestone {
+            title: &milestone.name,
+            description: &milestone.description,
+            due_on: Self::format_date(&milestone.deadline),
+        };
+
+        let response = self
+            .client
+            .send(
+                self.client
+                    .post(self.repo_url("milestones"))
+                    .json(&gitea_milestone),
+            )
+            .await?;
+
+        let created = response
+            .json::<GiteaMilestoneDetails>()
+            .await
+            .map_err(|e| {
+                ProviderError::Decode(format!("Failed to parse milestone response: {}", e))
+            })?;
+
+        self.milestones.insert(&milestone.name, created.id).await;
+
+        Ok(RemoteRef::new(created.id))
+    }
+
+    async fn create_issue_link(
+        &self,
+        from_id: i64,
+        to_id: i64,
+        kind: LinkKind,
+    ) -> Result<(), ProviderError> {
+        match kind {
+            // `from_id` dépend de `to_id` : `to_id` est ajouté aux dépendances de `from_id`
+            LinkKind::BlockedBy => self.add_dependency(from_id, to_id).await,
+            LinkKind::Blocks => self.add_dependency(to_id, from_id).await,
+            // Seules les dépendances sont natives, les autres liens sont notés en commentaire
+            LinkKind::RelatesTo | LinkKind::Parent | LinkKind::Child => {
+                self.comment_link(from_id, to_id, kind).await
+            }
+        }
+    }
+
+    async fn list_labels(&self) -> Result<Vec<Label>, ProviderError> {
+        Ok(self
+            .label_details()
+            .await?
+            .into_iter()
+            .map(Label::from)
+            .collect())
+    }
+
+    async fn list_milestones(&self) -> Result<Vec<RemoteMilestone>, ProviderError> {
+        let query = vec![
+            ("state", "all".to_string()),
+            ("limit", PAGE_LIMIT.to_string()),
+        ];
+
+        paginate::<GiteaMilestoneDetails>(
+            self.client.clone(),
+            self.repo_url("milestones"),
+            query,
+            Pagination::LinkHeader,
+        )
+        .map_ok(RemoteMilestone::from)
+        .try_collect()
+        .await
+    }
+
+    async fn list_issues(&self, filter: &IssueFilter) -> Result<Vec<RemoteIssue>, ProviderError> {
+        let state = match filter.state {
+            Some(IssueState::Open) => "open",
+            Some(IssueState::Closed) => "closed",
+            None => "all",
+        };
+        let mut query = vec![
+            ("state", state.to_string()),
+            // Exclut les pull requests
+            ("type", "issues".to_string()),
+            ("limit", PAGE_LIMIT.to_string()),
+        ];
+
+        if !filter.labels.is_empty() {
+            query.push(("labels", filter.labels.join(",")));
+        }
+
+        if let Some(milestone) = &filter.milestone {
+            query.push(("milestones", milestone.clone()));
+        }
+
+        paginate::<GiteaIssueDetails>(
+            self.client.clone(),
+            self.repo_url("issues"),
+            query,
+            Pagination::LinkHeader,
+        )
+        .map_ok(RemoteIssue::from)
+        .try_collect()
+        .await
+    }
+
+    async fn get_issue(&self, id: i64) -> Result<RemoteIssue, ProviderError> {
+        let issue: GiteaIssueDetails = self
+            .get_json(&self.repo_url(&format!("issues/{}", id)))
+            .await?;
+
+        Ok(issue.into())
+    }
+}
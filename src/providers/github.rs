@@ -1,9 +1,18 @@
+use futures::TryStreamExt;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 use reqwest::{header, Client};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use serde_json::json;
 
 use crate::error::ProviderError;
-use crate::models::common::{IssueCreate, Label, Milestone, Project};
+use crate::models::common::{
+    IssueCreate, IssueFilter, IssueState, Label, LinkKind, Milestone, RemoteIssue, RemoteMilestone,
+    RemoteRef,
+};
+use crate::providers::cache::MilestoneCache;
+use crate::providers::http::HttpClient;
+use crate::providers::pagination::{paginate, Pagination};
 use crate::providers::ProviderConfig;
 use crate::traits::repository::RepositoryProvider;
 
@@ -16,6 +25,12 @@ struct GitHubLabel<'a> {
     description: Option<&'a str>,
 }
 
+#[derive(Debug, Serialize)]
+struct GitHubLabelUpdate<'a> {
+    color: String, // Sans le #
+    description: Option<&'a str>,
+}
+
 #[derive(Debug, Serialize)]
 struct GitHubMilestone<'a> {
     title: &'a str,
@@ -25,11 +40,6 @@ struct GitHubMilestone<'a> {
     state: &'a str,
 }
 
-#[derive(Debug, Deserialize)]
-struct GitHubMilestoneResponse {
-    number: i64, // GitHub utilise 'number' plutôt que 'id'
-}
-
 #[derive(Debug, Serialize)]
 struct GitHubIssue {
     title: String,
@@ -39,15 +49,127 @@ struct GitHubIssue {
     assignees: Vec<String>,
 }
 
+/// Réponse à la création d'un label, d'un milestone ou d'une issue
+#[derive(Debug, Deserialize)]
+struct GitHubCreated {
+    id: i64,
+    node_id: Option<String>,
+    // Absents pour les labels
+    number: Option<i64>,
+    html_url: Option<String>,
+}
+
+impl From<GitHubCreated> for RemoteRef {
+    fn from(created: GitHubCreated) -> Self {
+        // Les milestones et issues sont désignés par leur 'number' dans l'API
+        match created.number {
+            Some(number) => RemoteRef {
+                id: number,
+                global_id: Some(created.id),
+                web_url: created.html_url,
+                node_id: created.node_id,
+            },
+            None => RemoteRef {
+                id: created.id,
+                global_id: None,
+                web_url: created.html_url,
+                node_id: created.node_id,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubLabelResponse {
+    name: String,
+    color: String,
+    description: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
-struct GitHubIssueResponse {
+struct GitHubMilestoneDetails {
     number: i64,
+    title: String,
+    description: Option<String>,
+    due_on: Option<String>,
+    state: IssueState,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubIssueDetails {
+    number: i64,
+    title: String,
+    body: Option<String>,
+    state: IssueState,
+    labels: Vec<GitHubLabelResponse>,
+    milestone: Option<GitHubMilestoneDetails>,
+    html_url: String,
+    // Présent uniquement lorsque l'issue est une pull request
+    pull_request: Option<serde_json::Value>,
+}
+
+/// Issue retournée par les API des dépendances et des sous-issues
+#[derive(Debug, Deserialize)]
+struct GitHubIssueRef {
+    id: i64,
+    number: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubIssueBody {
+    body: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct GitHubBody<'a> {
+    body: &'a str,
+}
+
+impl From<GitHubLabelResponse> for Label {
+    fn from(label: GitHubLabelResponse) -> Self {
+        Label {
+            name: label.name,
+            color: format!("#{}", label.color),
+            description: label.description,
+        }
+    }
+}
+
+impl From<GitHubMilestoneDetails> for RemoteMilestone {
+    fn from(milestone: GitHubMilestoneDetails) -> Self {
+        RemoteMilestone {
+            id: milestone.number,
+            title: milestone.title,
+            description: milestone.description,
+            // GitHub retourne un timestamp complet, on ne garde que la date
+            due_date: milestone
+                .due_on
+                .map(|due_on| due_on.chars().take(10).collect()),
+            state: milestone.state,
+        }
+    }
+}
+
+impl From<GitHubIssueDetails> for RemoteIssue {
+    fn from(issue: GitHubIssueDetails) -> Self {
+        RemoteIssue {
+            id: issue.number,
+            title: issue.title,
+            description: issue.body.unwrap_or_default(),
+            state: issue.state,
+            labels: issue.labels.into_iter().map(|label| label.name).collect(),
+            milestone: issue.milestone.map(|milestone| milestone.title),
+            web_url: issue.html_url,
+        }
+    }
 }
 
 pub struct GitHubProvider {
-    client: Client,
+    client: HttpClient,
     api_url: String,
     repo: String,
+    /// Numéros des milestones par titre, chargés à la première recherche
+    milestones: MilestoneCache,
 }
 
 impl GitHubProvider {
@@ -86,9 +208,10 @@ impl GitHubProvider {
             .map_err(|e| ProviderError::Network(e.to_string()))?;
 
         Ok(Self {
-            client,
+            client: HttpClient::new(client, config.retry),
             api_url: config.api_url,
             repo: config.repository,
+            milestones: MilestoneCache::default(),
         })
     }
 
@@ -99,11 +222,173 @@ impl GitHubProvider {
     fn strip_hash_from_color(color: &str) -> String {
         color.trim_start_matches('#').to_string()
     }
+
+    fn label_url(&self, name: &str) -> String {
+        format!(
+            "{}/repos/{}/labels/{}",
+            self.api_url,
+            self.repo,
+            utf8_percent_encode(name, NON_ALPHANUMERIC)
+        )
+    }
+
+    async fn get_json<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        query: &[(&str, String)],
+    ) -> Result<T, ProviderError> {
+        let response = self.client.send(self.client.get(url).query(query)).await?;
+
+        response
+            .json::<T>()
+            .await
+            .map_err(|e| ProviderError::Decode(format!("Failed to parse response: {}", e)))
+    }
+
+    fn issue_url(&self, number: i64, resource: &str) -> String {
+        format!(
+            "{}/repos/{}/issues/{}/{}",
+            self.api_url, self.repo, number, resource
+        )
+    }
+
+    /// Identifiant global d'une issue, attendu par les API des relations
+    async fn issue_id(&self, number: i64) -> Result<i64, ProviderError> {
+        let url = format!("{}/repos/{}/issues/{}", self.api_url, self.repo, number);
+        let issue: GitHubIssueRef = self.get_json(&url, &[]).await?;
+
+        Ok(issue.id)
+    }
+
+    /// Issues liées par une API de relations, `None` si l'instance ne la propose pas
+    async fn related_issues(
+        &self,
+        url: &str,
+    ) -> Result<Option<Vec<GitHubIssueRef>>, ProviderError> {
+        let related = paginate::<GitHubIssueRef>(
+            self.client.clone(),
+            url.to_string(),
+            Vec::new(),
+            Pagination::LinkHeader,
+        )
+        .try_collect()
+        .await;
+
+        match related {
+            Ok(issues) => Ok(Some(issues)),
+            // GitHub Enterprise Server n'expose pas encore ces API
+            Err(e) if e.status() == Some(404) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Note le lien dans un commentaire, par exemple `Depends on #N`
+    async fn comment_link(
+        &self,
+        from_id: i64,
+        to_id: i64,
+        kind: LinkKind,
+    ) -> Result<(), ProviderError> {
+        let url = self.issue_url(from_id, "comments");
+        let comment = kind.describe(to_id);
+
+        // Ne pas dupliquer le commentaire si le lien existe déjà
+        let comments: Vec<GitHubIssueBody> = paginate(
+            self.client.clone(),
+            url.clone(),
+            Vec::new(),
+            Pagination::LinkHeader,
+        )
+        .try_collect()
+        .await?;
+        if comments
+            .iter()
+            .any(|c| c.body.as_deref().map(str::trim) == Some(comment.as_str()))
+        {
+            return Ok(());
+        }
+
+        self.client
+            .send(self.client.post(&url).json(&GitHubBody { body: &comment }))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Ajoute `blocking_id` aux issues qui bloquent `issue_id`.
+    ///
+    /// Sans API des dépendances, la dépendance est notée en commentaire.
+    async fn add_blocked_by(&self, issue_id: i64, blocking_id: i64) -> Result<(), ProviderError> {
+        let url = self.issue_url(issue_id, "dependencies/blocked_by");
+
+        let Some(blocking) = self.related_issues(&url).await? else {
+            return self
+                .comment_link(issue_id, blocking_id, LinkKind::BlockedBy)
+                .await;
+        };
+        if blocking.iter().any(|issue| issue.number == blocking_id) {
+            return Ok(());
+        }
+
+        let body = json!({ "issue_id": self.issue_id(blocking_id).await? });
+        self.client.send(self.client.post(&url).json(&body)).await?;
+
+        Ok(())
+    }
+
+    /// Rattache `child_id` à l'issue `parent_id` en tant que sous-issue.
+    ///
+    /// Sans API des sous-issues, `child_id` est ajoutée à la liste de tâches
+    /// du corps du parent.
+    async fn add_sub_issue(&self, parent_id: i64, child_id: i64) -> Result<(), ProviderError> {
+        let url = self.issue_url(parent_id, "sub_issues");
+
+        let Some(children) = self.related_issues(&url).await? else {
+            return self.add_task(parent_id, child_id).await;
+        };
+        if children.iter().any(|child| child.number == child_id) {
+            return Ok(());
+        }
+
+        let body = json!({ "sub_issue_id": self.issue_id(child_id).await? });
+        self.client.send(self.client.post(&url).json(&body)).await?;
+
+        Ok(())
+    }
+
+    /// Ajoute `- [ ] #child_id` à la fin du corps de l'issue `parent_id`
+    async fn add_task(&self, parent_id: i64, child_id: i64) -> Result<(), ProviderError> {
+        let url = format!("{}/repos/{}/issues/{}", self.api_url, self.repo, parent_id);
+        let issue: GitHubIssueBody = self.get_json(&url, &[]).await?;
+        let body = issue.body.unwrap_or_default();
+
+        let reference = format!("#{}", child_id);
+        let listed = body.lines().any(|line| {
+            ["- [ ]", "- [x]"].iter().any(|prefix| {
+                line.trim().strip_prefix(prefix).map(str::trim) == Some(reference.as_str())
+            })
+        });
+        if listed {
+            return Ok(());
+        }
+
+        let body = format!("{}\n- [ ] {}", body.trim_end(), reference);
+        self.client
+            .send(self.client.patch(&url).json(&GitHubBody { body: &body }))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Numéro du milestone portant ce titre
+    async fn milestone_number(&self, title: &str) -> Result<Option<i64>, ProviderError> {
+        self.milestones.id(self, title).await
+    }
 }
 
 #[async_trait::async_trait]
 impl RepositoryProvider for GitHubProvider {
-    async fn create_label(&self, label: &Label) -> Result<(), ProviderError> {
+    async fn create_label(&self, label: &Label) -> Result<Option<RemoteRef>, ProviderError> {
         let url = format!("{}/repos/{}/labels", self.api_url, self.repo,);
 
         let github_label = GitHubLabel {
@@ -114,29 +399,43 @@ impl RepositoryProvider for GitHubProvider {
 
         let response = self
             .client
-            .post(&url)
-            .json(&github_label)
-            .send()
+            .send(self.client.post(&url).json(&github_label))
+            .await?;
+
+        let created = response
+            .json::<GitHubCreated>()
             .await
-            .map_err(|e| ProviderError::Network(e.to_string()))?;
+            .map_err(|e| ProviderError::Decode(format!("Failed to parse label response: {}", e)))?;
 
-        if !response.status().is_success() {
-            let status = response.status();
-            let error_body = response
-                .text()
-                .await
-                .unwrap_or_else(|_| String::from("Unable to read error response"));
-
-            return Err(ProviderError::Api(format!(
-                "Failed to create label. Status: {}, Body: {}",
-                status, error_body
-            )));
-        }
+        Ok(Some(created.into()))
+    }
+
+    async fn update_label(&self, label: &Label) -> Result<(), ProviderError> {
+        let github_label = GitHubLabelUpdate {
+            color: Self::strip_hash_from_color(&label.color),
+            description: label.description.as_deref(),
+        };
+
+        self.client
+            .send(
+                self.client
+                    .patch(self.label_url(&label.name))
+                    .json(&github_label),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete_label(&self, name: &str) -> Result<(), ProviderError> {
+        self.client
+            .send(self.client.delete(self.label_url(name)))
+            .await?;
 
         Ok(())
     }
 
-    async fn create_milestone(&self, milestone: &Milestone) -> Result<i64, ProviderError> {
+    async fn create_milestone(&self, milestone: &Milestone) -> Result<RemoteRef, ProviderError> {
         let url = format!("{}/repos/{}/milestones", self.api_url, self.repo,);
 
         let github_milestone = GitHubMilestone {
@@ -149,193 +448,130 @@ impl RepositoryProvider for GitHubProvider {
 
         let response = self
             .client
-            .post(&url)
-            .json(&github_milestone)
-            .send()
-            .await
-            .map_err(|e| ProviderError::Network(e.to_string()))?;
+            .send(self.client.post(&url).json(&github_milestone))
+            .await?;
 
-        if !response.status().is_success() {
-            let status = response.status(); // Récupérer le statut avant d'appeler `.text()`
-            let error_body = response
-                .text()
-                .await
-                .unwrap_or_else(|_| String::from("Unable to read error response"));
-
-            return Err(ProviderError::Api(format!(
-                "Failed to create label. Status: {}, Body: {}",
-                status, // Utiliser `status` ici
-                error_body
-            )));
-        }
+        let created = response.json::<GitHubCreated>().await.map_err(|e| {
+            ProviderError::Decode(format!("Failed to parse milestone response: {}", e))
+        })?;
+        let created = RemoteRef::from(created);
 
-        let milestone_response = response
-            .json::<GitHubMilestoneResponse>()
-            .await
-            .map_err(|e| {
-                ProviderError::Api(format!("Failed to parse milestone response: {}", e))
-            })?;
+        self.milestones.insert(&milestone.name, created.id).await;
 
-        Ok(milestone_response.number)
+        Ok(created)
     }
 
-    async fn create_issue(&self, issue: &IssueCreate) -> Result<(), ProviderError> {
+    async fn create_issue(&self, issue: &IssueCreate) -> Result<RemoteRef, ProviderError> {
         let url = format!("{}/repos/{}/issues", self.api_url, self.repo,);
 
+        let milestone = match (issue.milestone_id, &issue.milestone) {
+            (Some(number), _) => Some(number),
+            (None, Some(title)) => {
+                Some(self.milestone_number(title).await?.ok_or_else(|| {
+                    ProviderError::Config(format!("Milestone not found: {}", title))
+                })?)
+            }
+            (None, None) => None,
+        };
+
+        // GitHub désigne les personnes assignées par leur login ; l'échéance
+        // et le poids n'ont pas d'équivalent
         let github_issue = GitHubIssue {
             title: issue.title.clone(),
             body: issue.description.clone(),
-            milestone: None,
+            milestone,
             labels: issue.labels.clone(),
-            assignees: Vec::new(), // Optionnel, pourrait être ajouté plus tard
+            assignees: issue.assignees.clone(),
         };
 
         let response = self
             .client
-            .post(&url)
-            .json(&github_issue)
-            .send()
-            .await
-            .map_err(|e| ProviderError::Network(e.to_string()))?;
+            .send(self.client.post(&url).json(&github_issue))
+            .await?;
 
-        if !response.status().is_success() {
-            let status = response.status(); // Récupérer le statut avant d'appeler `.text()`
-            let error_body = response
-                .text()
-                .await
-                .unwrap_or_else(|_| String::from("Unable to read error response"));
-
-            return Err(ProviderError::Api(format!(
-                "Failed to create label. Status: {}, Body: {}",
-                status, // Utiliser `status` ici
-                error_body
-            )));
-        }
+        let created = response
+            .json::<GitHubCreated>()
+            .await
+            .map_err(|e| ProviderError::Decode(format!("Failed to parse issue response: {}", e)))?;
 
-        Ok(())
+        Ok(created.into())
     }
 
-    async fn create_issue_link(&self, from_id: i64, to_id: i64) -> Result<(), ProviderError> {
-        // GitHub n'a pas d'API native pour les liens entre issues
-        // On ajoute un commentaire pour montrer la dépendance
-        let url = format!(
-            "{}/repos/{}/issues/{}/comments",
-            self.api_url, self.repo, from_id
-        );
-
-        let comment = format!("Depends on #{}", to_id);
-
-        #[derive(Serialize)]
-        struct CommentBody<'a> {
-            body: &'a str,
+    async fn create_issue_link(
+        &self,
+        from_id: i64,
+        to_id: i64,
+        kind: LinkKind,
+    ) -> Result<(), ProviderError> {
+        match kind {
+            LinkKind::BlockedBy => self.add_blocked_by(from_id, to_id).await,
+            LinkKind::Blocks => self.add_blocked_by(to_id, from_id).await,
+            LinkKind::Parent => self.add_sub_issue(from_id, to_id).await,
+            LinkKind::Child => self.add_sub_issue(to_id, from_id).await,
+            // GitHub n'a pas de relation sans contrainte : le lien est noté en commentaire
+            LinkKind::RelatesTo => self.comment_link(from_id, to_id, kind).await,
         }
+    }
 
-        let response = self
-            .client
-            .post(&url)
-            .json(&CommentBody { body: &comment })
-            .send()
-            .await
-            .map_err(|e| ProviderError::Network(e.to_string()))?;
+    async fn list_labels(&self) -> Result<Vec<Label>, ProviderError> {
+        let url = format!("{}/repos/{}/labels", self.api_url, self.repo);
+
+        paginate::<GitHubLabelResponse>(
+            self.client.clone(),
+            url,
+            Vec::new(),
+            Pagination::LinkHeader,
+        )
+        .map_ok(Label::from)
+        .try_collect()
+        .await
+    }
 
-        if !response.status().is_success() {
-            let status = response.status(); // Récupérer le statut avant d'appeler `.text()`
-            let error_body = response
-                .text()
-                .await
-                .unwrap_or_else(|_| String::from("Unable to read error response"));
-
-            return Err(ProviderError::Api(format!(
-                "Failed to create label. Status: {}, Body: {}",
-                status, // Utiliser `status` ici
-                error_body
-            )));
-        }
+    async fn list_milestones(&self) -> Result<Vec<RemoteMilestone>, ProviderError> {
+        let url = format!("{}/repos/{}/milestones", self.api_url, self.repo);
+        let query = vec![("state", "all".to_string())];
 
-        Ok(())
+        paginate::<GitHubMilestoneDetails>(self.client.clone(), url, query, Pagination::LinkHeader)
+            .map_ok(RemoteMilestone::from)
+            .try_collect()
+            .await
     }
 
-    async fn setup_project(&self, project: &Project) -> Result<(), ProviderError> {
-        println!("Setting up project in GitHub: {}", project.name);
+    async fn list_issues(&self, filter: &IssueFilter) -> Result<Vec<RemoteIssue>, ProviderError> {
+        let url = format!("{}/repos/{}/issues", self.api_url, self.repo);
+
+        let state = match filter.state {
+            Some(IssueState::Open) => "open",
+            Some(IssueState::Closed) => "closed",
+            None => "all",
+        };
+        let mut query = vec![("state", state.to_string())];
 
-        // 1. Créer les milestones
-        let mut milestone_ids = HashMap::new();
-        for milestone in &project.milestones {
-            println!("Creating milestone: {}", milestone.name);
-            let id = self.create_milestone(milestone).await?;
-            milestone_ids.insert(&milestone.version, id);
+        if !filter.labels.is_empty() {
+            query.push(("labels", filter.labels.join(",")));
         }
 
-        // 2. Créer les issues
-        let mut issue_ids = HashMap::new();
-        for issue in &project.issues {
-            println!("Creating issue: {}", issue.title);
-
-            let milestone_id = milestone_ids.get(&issue.milestone).ok_or_else(|| {
-                ProviderError::NotFound(format!("Milestone not found: {}", issue.milestone))
-            })?;
-
-            let description = issue
-                .description
-                .sections
-                .iter()
-                .map(|s| format!("{}\n{}", s.title, s.content.join("\n")))
-                .collect::<Vec<_>>()
-                .join("\n\n");
-
-            let github_issue = GitHubIssue {
-                title: issue.title.clone(),
-                body: description,
-                milestone: Some(*milestone_id),
-                labels: issue.labels.clone(),
-                assignees: Vec::new(),
+        // GitHub filtre par numéro de milestone, pas par titre
+        if let Some(title) = &filter.milestone {
+            let Some(number) = self.milestone_number(title).await? else {
+                // Aucune issue ne peut appartenir à un milestone inexistant
+                return Ok(Vec::new());
             };
+            query.push(("milestone", number.to_string()));
+        }
 
-            let url = format!("{}/repos/{}/issues", self.api_url, self.repo,);
-
-            let response = self
-                .client
-                .post(&url)
-                .json(&github_issue)
-                .send()
-                .await
-                .map_err(|e| ProviderError::Network(e.to_string()))?;
-
-            if !response.status().is_success() {
-                let status = response.status(); // Récupérer le statut avant d'appeler `.text()`
-                let error_body = response
-                    .text()
-                    .await
-                    .unwrap_or_else(|_| String::from("Unable to read error response"));
-
-                return Err(ProviderError::Api(format!(
-                    "Failed to create label. Status: {}, Body: {}",
-                    status, // Utiliser `status` ici
-                    error_body
-                )));
-            }
-
-            let issue_response = response
-                .json::<GitHubIssueResponse>()
-                .await
-                .map_err(|e| ProviderError::Api(e.to_string()))?;
+        paginate::<GitHubIssueDetails>(self.client.clone(), url, query, Pagination::LinkHeader)
+            .try_filter(|issue| futures::future::ready(issue.pull_request.is_none()))
+            .map_ok(RemoteIssue::from)
+            .try_collect()
+            .await
+    }
 
-            issue_ids.insert(&issue.title, issue_response.number);
-        }
+    async fn get_issue(&self, id: i64) -> Result<RemoteIssue, ProviderError> {
+        let url = format!("{}/repos/{}/issues/{}", self.api_url, self.repo, id);
 
-        // 3. Créer les liens entre les issues
-        for issue in &project.issues {
-            if let Some(&from_id) = issue_ids.get(&issue.title) {
-                for dep in &issue.dependencies {
-                    if let Some(&to_id) = issue_ids.get(dep) {
-                        println!("Creating link from #{} to #{}", from_id, to_id);
-                        self.create_issue_link(from_id, to_id).await?;
-                    }
-                }
-            }
-        }
+        let issue: GitHubIssueDetails = self.get_json(&url, &[]).await?;
 
-        println!("Project setup completed successfully!");
-        Ok(())
+        Ok(issue.into())
     }
 }
@@ -1,17 +1,137 @@
 use async_trait::async_trait;
-use percent_encoding::{percent_encode, NON_ALPHANUMERIC};
+use futures::TryStreamExt;
+use percent_encoding::{percent_encode, utf8_percent_encode, NON_ALPHANUMERIC};
 use reqwest::{header, Client};
-use std::collections::HashMap;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
 
 use crate::error::ProviderError;
-use crate::models::common::{IssueCreate, Label, Milestone, Project};
+use crate::models::common::{
+    IssueCreate, IssueFilter, IssueState, Label, LinkKind, Milestone, RemoteIssue, RemoteMilestone,
+    RemoteRef,
+};
+use crate::providers::cache::MilestoneCache;
+use crate::providers::http::HttpClient;
+use crate::providers::pagination::{paginate, Pagination};
 use crate::providers::ProviderConfig;
 use crate::traits::repository::RepositoryProvider;
 
+#[derive(Debug, Deserialize)]
+struct GitLabMilestoneDetails {
+    id: i64,
+    title: String,
+    description: Option<String>,
+    due_date: Option<String>,
+    state: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct GitLabIssue<'a> {
+    title: &'a str,
+    description: &'a str,
+    labels: &'a [String],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    milestone_id: Option<i64>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    assignee_ids: Vec<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    due_date: Option<&'a str>,
+    // Le poids n'est disponible que sur les offres payantes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    weight: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabUser {
+    id: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabMilestoneRef {
+    title: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabIssueDetails {
+    iid: i64,
+    title: String,
+    description: Option<String>,
+    state: String,
+    labels: Vec<String>,
+    milestone: Option<GitLabMilestoneRef>,
+    web_url: String,
+}
+
+/// Réponse à la création d'un label, d'un milestone ou d'une issue
+#[derive(Debug, Deserialize)]
+struct GitLabCreated {
+    id: i64,
+    // Présents pour les milestones et les issues
+    iid: Option<i64>,
+    web_url: Option<String>,
+}
+
+impl GitLabCreated {
+    /// Les issues sont désignées par leur iid, les labels et milestones par leur id
+    fn issue_ref(self) -> RemoteRef {
+        RemoteRef {
+            id: self.iid.unwrap_or(self.id),
+            global_id: Some(self.id),
+            web_url: self.web_url,
+            node_id: None,
+        }
+    }
+
+    fn global_ref(self) -> RemoteRef {
+        RemoteRef {
+            id: self.id,
+            global_id: None,
+            web_url: self.web_url,
+            node_id: None,
+        }
+    }
+}
+
+// GitLab utilise "opened"/"active" pour les issues et milestones ouverts
+fn parse_state(state: &str) -> IssueState {
+    match state {
+        "closed" => IssueState::Closed,
+        _ => IssueState::Open,
+    }
+}
+
+impl From<GitLabMilestoneDetails> for RemoteMilestone {
+    fn from(milestone: GitLabMilestoneDetails) -> Self {
+        RemoteMilestone {
+            id: milestone.id,
+            title: milestone.title,
+            description: milestone.description,
+            due_date: milestone.due_date,
+            state: parse_state(&milestone.state),
+        }
+    }
+}
+
+impl From<GitLabIssueDetails> for RemoteIssue {
+    fn from(issue: GitLabIssueDetails) -> Self {
+        RemoteIssue {
+            id: issue.iid,
+            title: issue.title,
+            description: issue.description.unwrap_or_default(),
+            state: parse_state(&issue.state),
+            labels: issue.labels,
+            milestone: issue.milestone.map(|milestone| milestone.title),
+            web_url: issue.web_url,
+        }
+    }
+}
+
 pub struct GitLabProvider {
-    client: Client,
+    client: HttpClient,
     api_url: String,
     project_id: String,
+    /// Identifiants globaux des milestones par titre, chargés à la première recherche
+    milestones: MilestoneCache,
 }
 
 impl GitLabProvider {
@@ -29,70 +149,149 @@ impl GitLabProvider {
             .map_err(|e| ProviderError::Network(e.to_string()))?;
 
         Ok(Self {
-            client,
+            client: HttpClient::new(client, config.retry),
             api_url: config.api_url,
             project_id: config.repository,
+            milestones: MilestoneCache::default(),
         })
     }
 
     fn encode_project_id(&self) -> String {
         percent_encode(self.project_id.as_bytes(), NON_ALPHANUMERIC).to_string()
     }
+
+    fn label_url(&self, name: &str) -> String {
+        format!(
+            "{}/projects/{}/labels/{}",
+            self.api_url,
+            self.encode_project_id(),
+            utf8_percent_encode(name, NON_ALPHANUMERIC)
+        )
+    }
+
+    async fn get_json<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        query: &[(&str, String)],
+    ) -> Result<T, ProviderError> {
+        let response = self.client.send(self.client.get(url).query(query)).await?;
+
+        response
+            .json::<T>()
+            .await
+            .map_err(|e| ProviderError::Decode(e.to_string()))
+    }
+
+    /// Identifiant global du milestone portant ce titre
+    async fn milestone_id(&self, title: &str) -> Result<i64, ProviderError> {
+        self.milestones.require(self, title).await
+    }
+
+    /// Identifiant de l'utilisateur portant ce nom
+    async fn user_id(&self, username: &str) -> Result<i64, ProviderError> {
+        let users: Vec<GitLabUser> = self
+            .get_json(
+                &format!("{}/users", self.api_url),
+                &[("username", username.to_string())],
+            )
+            .await?;
+
+        users
+            .first()
+            .map(|user| user.id)
+            .ok_or_else(|| ProviderError::Config(format!("User not found: {}", username)))
+    }
+
+    async fn post_json<B: serde::Serialize + ?Sized>(
+        &self,
+        url: &str,
+        body: &B,
+    ) -> Result<GitLabCreated, ProviderError> {
+        let response = self.client.send(self.client.post(url).json(body)).await?;
+
+        response
+            .json::<GitLabCreated>()
+            .await
+            .map_err(|e| ProviderError::Decode(e.to_string()))
+    }
 }
 
 #[async_trait]
 impl RepositoryProvider for GitLabProvider {
-    async fn create_label(&self, label: &Label) -> Result<(), ProviderError> {
+    async fn create_label(&self, label: &Label) -> Result<Option<RemoteRef>, ProviderError> {
         let url = format!(
             "{}/projects/{}/labels",
             self.api_url,
             self.encode_project_id()
         );
 
-        let response = self
-            .client
-            .post(&url)
-            .json(&label)
-            .send()
-            .await
-            .map_err(|e| ProviderError::Network(e.to_string()))?;
+        let created = self.post_json(&url, label).await?;
 
-        if !response.status().is_success() {
-            return Err(ProviderError::Api(format!(
-                "Failed to create label: {}",
-                response.status()
-            )));
+        Ok(Some(created.global_ref()))
+    }
+
+    async fn update_label(&self, label: &Label) -> Result<(), ProviderError> {
+        #[derive(serde::Serialize)]
+        struct GitLabLabelUpdate<'a> {
+            color: &'a str,
+            description: &'a str,
         }
 
+        let update = GitLabLabelUpdate {
+            color: &label.color,
+            // Une description vide efface celle du label
+            description: label.description.as_deref().unwrap_or_default(),
+        };
+
+        self.client
+            .send(self.client.put(self.label_url(&label.name)).json(&update))
+            .await?;
+
         Ok(())
     }
 
-    async fn create_issue(&self, issue: &IssueCreate) -> Result<(), ProviderError> {
+    async fn delete_label(&self, name: &str) -> Result<(), ProviderError> {
+        self.client
+            .send(self.client.delete(self.label_url(name)))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn create_issue(&self, issue: &IssueCreate) -> Result<RemoteRef, ProviderError> {
         let url = format!(
             "{}/projects/{}/issues",
             self.api_url,
             self.encode_project_id()
         );
 
-        let response = self
-            .client
-            .post(&url)
-            .json(&issue)
-            .send()
-            .await
-            .map_err(|e| ProviderError::Network(e.to_string()))?;
+        let milestone_id = match (issue.milestone_id, &issue.milestone) {
+            (Some(id), _) => Some(id),
+            (None, Some(title)) => Some(self.milestone_id(title).await?),
+            (None, None) => None,
+        };
 
-        if !response.status().is_success() {
-            return Err(ProviderError::Api(format!(
-                "Failed to create issue: {}",
-                response.status()
-            )));
+        let mut assignee_ids = Vec::with_capacity(issue.assignees.len());
+        for username in &issue.assignees {
+            assignee_ids.push(self.user_id(username).await?);
         }
 
-        Ok(())
+        let gitlab_issue = GitLabIssue {
+            title: &issue.title,
+            description: &issue.description,
+            labels: &issue.labels,
+            milestone_id,
+            assignee_ids,
+            due_date: issue.due_date.as_deref(),
+            weight: issue.weight,
+        };
+
+        let created = self.post_json(&url, &gitlab_issue).await?;
+
+        Ok(created.issue_ref())
     }
 
-    async fn create_milestone(&self, milestone: &Milestone) -> Result<i64, ProviderError> {
+    async fn create_milestone(&self, milestone: &Milestone) -> Result<RemoteRef, ProviderError> {
         let url = format!(
             "{}/projects/{}/milestones",
             self.api_url,
@@ -112,144 +311,124 @@ impl RepositoryProvider for GitLabProvider {
             due_date: &milestone.deadline,
         };
 
-        #[derive(serde::Deserialize)]
-        struct MilestoneResponse {
-            id: i64,
-        }
+        // Les issues sont rattachées au milestone par son id global
+        let created = self.post_json(&url, &gitlab_milestone).await?.global_ref();
 
-        let response = self
-            .client
-            .post(&url)
-            .json(&gitlab_milestone)
-            .send()
-            .await
-            .map_err(|e| ProviderError::Network(e.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(ProviderError::Api(format!(
-                "Failed to create milestone: {}",
-                response.status()
-            )));
-        }
+        self.milestones.insert(&milestone.name, created.id).await;
 
-        let milestone_response = response
-            .json::<MilestoneResponse>()
-            .await
-            .map_err(|e| ProviderError::Api(e.to_string()))?;
-
-        Ok(milestone_response.id)
+        Ok(created)
     }
 
-    async fn create_issue_link(&self, from_id: i64, to_id: i64) -> Result<(), ProviderError> {
+    async fn create_issue_link(
+        &self,
+        from_id: i64,
+        to_id: i64,
+        kind: LinkKind,
+    ) -> Result<(), ProviderError> {
         let url = format!(
-            "{}/projects/{}/issues/{}/links?target_issue_id={}",
+            "{}/projects/{}/issues/{}/links",
             self.api_url,
             self.encode_project_id(),
-            from_id,
-            to_id
+            from_id
         );
 
-        let response = self
-            .client
-            .post(&url)
-            .send()
-            .await
-            .map_err(|e| ProviderError::Network(e.to_string()))?;
+        // Les liens d'issues GitLab n'ont pas de hiérarchie, réservée aux epics
+        // de groupe : parent et enfant deviennent des liens simples
+        let link_type = match kind {
+            LinkKind::Blocks => "blocks",
+            LinkKind::BlockedBy => "is_blocked_by",
+            LinkKind::RelatesTo | LinkKind::Parent | LinkKind::Child => "relates_to",
+        };
 
-        if !response.status().is_success() {
-            return Err(ProviderError::Api(format!(
-                "Failed to create issue link: {}",
-                response.status()
-            )));
+        // Les deux issues appartiennent au même projet
+        let query = [
+            ("target_project_id", self.project_id.clone()),
+            ("target_issue_iid", to_id.to_string()),
+            ("link_type", link_type.to_string()),
+        ];
+
+        match self.client.send(self.client.post(&url).query(&query)).await {
+            Ok(_) => Ok(()),
+            // GitLab répond 409 lorsque les issues sont déjà liées
+            Err(e) if e.status() == Some(409) => Ok(()),
+            Err(e) => Err(e),
         }
+    }
 
-        Ok(())
+    async fn list_labels(&self) -> Result<Vec<Label>, ProviderError> {
+        let url = format!(
+            "{}/projects/{}/labels",
+            self.api_url,
+            self.encode_project_id()
+        );
+
+        paginate(
+            self.client.clone(),
+            url,
+            Vec::new(),
+            Pagination::PageHeaders,
+        )
+        .try_collect()
+        .await
     }
 
-    async fn setup_project(&self, project: &Project) -> Result<(), ProviderError> {
-        let mut milestone_ids = HashMap::new();
+    async fn list_milestones(&self) -> Result<Vec<RemoteMilestone>, ProviderError> {
+        let url = format!(
+            "{}/projects/{}/milestones",
+            self.api_url,
+            self.encode_project_id()
+        );
+
+        paginate::<GitLabMilestoneDetails>(
+            self.client.clone(),
+            url,
+            Vec::new(),
+            Pagination::PageHeaders,
+        )
+        .map_ok(RemoteMilestone::from)
+        .try_collect()
+        .await
+    }
 
-        // Créer les milestones
-        for milestone in &project.milestones {
-            let id = self.create_milestone(milestone).await?;
-            milestone_ids.insert(&milestone.version, id);
+    async fn list_issues(&self, filter: &IssueFilter) -> Result<Vec<RemoteIssue>, ProviderError> {
+        let url = format!(
+            "{}/projects/{}/issues",
+            self.api_url,
+            self.encode_project_id()
+        );
+
+        let mut query = Vec::new();
+
+        match filter.state {
+            Some(IssueState::Open) => query.push(("state", "opened".to_string())),
+            Some(IssueState::Closed) => query.push(("state", "closed".to_string())),
+            None => {}
         }
 
-        // Créer les issues avec leurs milestones
-        let mut issue_ids = HashMap::new();
-        for issue in &project.issues {
-            let milestone_id = milestone_ids.get(&issue.milestone).ok_or_else(|| {
-                ProviderError::NotFound(format!("Milestone not found: {}", issue.milestone))
-            })?;
-
-            let description = issue
-                .description
-                .sections
-                .iter()
-                .map(|s| format!("{}\n{}", s.title, s.content.join("\n")))
-                .collect::<Vec<_>>()
-                .join("\n\n");
-
-            #[derive(serde::Serialize)]
-            struct GitLabIssue<'a> {
-                title: &'a str,
-                description: String,
-                milestone_id: i64,
-                labels: &'a [String],
-            }
-
-            let url = format!(
-                "{}/projects/{}/issues",
-                self.api_url,
-                self.encode_project_id()
-            );
-
-            let gitlab_issue = GitLabIssue {
-                title: &issue.title,
-                description,
-                milestone_id: *milestone_id,
-                labels: &issue.labels,
-            };
-
-            let response = self
-                .client
-                .post(&url)
-                .json(&gitlab_issue)
-                .send()
-                .await
-                .map_err(|e| ProviderError::Network(e.to_string()))?;
-
-            if !response.status().is_success() {
-                return Err(ProviderError::Api(format!(
-                    "Failed to create issue: {}",
-                    response.status()
-                )));
-            }
-
-            #[derive(serde::Deserialize)]
-            struct IssueResponse {
-                iid: i64,
-            }
-
-            let issue_response = response
-                .json::<IssueResponse>()
-                .await
-                .map_err(|e| ProviderError::Api(e.to_string()))?;
-
-            issue_ids.insert(&issue.title, issue_response.iid);
+        if !filter.labels.is_empty() {
+            query.push(("labels", filter.labels.join(",")));
         }
 
-        // Créer les liens entre les issues
-        for issue in &project.issues {
-            if let Some(&from_id) = issue_ids.get(&issue.title) {
-                for dep in &issue.dependencies {
-                    if let Some(&to_id) = issue_ids.get(dep) {
-                        self.create_issue_link(from_id, to_id).await?;
-                    }
-                }
-            }
+        if let Some(milestone) = &filter.milestone {
+            query.push(("milestone", milestone.clone()));
         }
 
-        Ok(())
+        paginate::<GitLabIssueDetails>(self.client.clone(), url, query, Pagination::PageHeaders)
+            .map_ok(RemoteIssue::from)
+            .try_collect()
+            .await
+    }
+
+    async fn get_issue(&self, id: i64) -> Result<RemoteIssue, ProviderError> {
+        let url = format!(
+            "{}/projects/{}/issues/{}",
+            self.api_url,
+            self.encode_project_id(),
+            id
+        );
+
+        let issue: GitLabIssueDetails = self.get_json(&url, &[]).await?;
+
+        Ok(issue.into())
     }
 }
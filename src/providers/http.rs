@@ -0,0 +1,233 @@
+use reqwest::header::HeaderMap;
+use reqwest::{Client, IntoUrl, Method, RequestBuilder, Response, StatusCode};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::error::{ApiError, ProviderError};
+
+/// Politique de retry appliquée à toutes les requêtes des providers
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Nombre maximum de nouvelles tentatives après une erreur 5xx, réseau ou
+    /// une limite de débit
+    pub max_retries: u32,
+    /// Délai de la première nouvelle tentative, doublé à chaque essai
+    pub base_delay: Duration,
+    /// Délai maximum entre deux tentatives
+    pub max_delay: Duration,
+    /// Temps d'attente cumulé accepté sur les limites de débit avant d'abandonner
+    pub rate_limit_budget: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            rate_limit_budget: Duration::from_secs(300),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Désactive les nouvelles tentatives : la première erreur est retournée
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            rate_limit_budget: Duration::ZERO,
+            ..Self::default()
+        }
+    }
+
+    /// Délai avant la tentative `attempt` (à partir de 0), avec un jitter
+    /// aléatoire entre la moitié et la totalité du backoff exponentiel
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponential = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay);
+
+        let half = exponential / 2;
+        half + half.mul_f64(jitter())
+    }
+}
+
+// Nombre pseudo-aléatoire dans [0, 1), suffisant pour désynchroniser les retries
+fn jitter() -> f64 {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or_default();
+
+    (nanos % 1_000) as f64 / 1_000.0
+}
+
+/// Client HTTP partagé par les providers : exécute les requêtes en gérant les
+/// retries sur erreurs transitoires et les limites de débit
+#[derive(Debug, Clone)]
+pub struct HttpClient {
+    client: Client,
+    retry: RetryPolicy,
+}
+
+impl HttpClient {
+    pub fn new(client: Client, retry: RetryPolicy) -> Self {
+        Self { client, retry }
+    }
+
+    pub fn get<U: IntoUrl>(&self, url: U) -> RequestBuilder {
+        self.client.get(url)
+    }
+
+    pub fn post<U: IntoUrl>(&self, url: U) -> RequestBuilder {
+        self.client.post(url)
+    }
+
+    pub fn put<U: IntoUrl>(&self, url: U) -> RequestBuilder {
+        self.client.put(url)
+    }
+
+    pub fn patch<U: IntoUrl>(&self, url: U) -> RequestBuilder {
+        self.client.patch(url)
+    }
+
+    pub fn delete<U: IntoUrl>(&self, url: U) -> RequestBuilder {
+        self.client.delete(url)
+    }
+
+    /// Envoie la requête et retourne la réponse si elle est en succès.
+    ///
+    /// Les erreurs réseau et 5xx transitoires sont rejouées pour les méthodes
+    /// idempotentes ; un POST n'est rejoué que si la connexion a échoué avant
+    /// l'envoi, pour ne jamais créer deux fois la même entité. Les limites de
+    /// débit sont attendues dans la limite du budget et du nombre de retries.
+    /// Les autres réponses en échec sont converties par [`ProviderError::from_response`].
+    pub async fn send(&self, request: RequestBuilder) -> Result<Response, ProviderError> {
+        let mut attempt = 0;
+        let mut rate_limit_wait = Duration::ZERO;
+        let mut pending = Some(request);
+
+        while let Some(request) = pending.take() {
+            // Une requête dont le corps est un stream ne peut pas être rejouée
+            let retry = request.try_clone();
+
+            let request = request
+                .build()
+                .map_err(|e| ProviderError::Network(e.to_string()))?;
+            let idempotent = is_idempotent(request.method());
+            let method = request.method().to_string();
+            let url = request.url().to_string();
+            let can_retry = retry.is_some() && attempt < self.retry.max_retries;
+
+            let response = match self.client.execute(request).await {
+                Ok(response) => response,
+                Err(e) if can_retry && (e.is_connect() || (idempotent && e.is_timeout())) => {
+                    tokio::time::sleep(self.retry.backoff(attempt)).await;
+                    attempt += 1;
+                    pending = retry;
+                    continue;
+                }
+                Err(e) => return Err(ProviderError::Network(e.to_string())),
+            };
+
+            let status = response.status();
+
+            if status.is_success() {
+                return Ok(response);
+            }
+
+            // Une requête refusée pour limite de débit n'a pas été traitée :
+            // elle peut être rejouée quelle que soit sa méthode
+            if is_rate_limited(status, response.headers()) {
+                // Un délai nul (`Retry-After: 0`, reset déjà passé) ferait boucler sans attendre
+                let wait = rate_limit_delay(response.headers())
+                    .unwrap_or_default()
+                    .max(self.retry.backoff(attempt));
+
+                if !can_retry || rate_limit_wait + wait > self.retry.rate_limit_budget {
+                    let body = response.text().await.unwrap_or_default();
+                    let mut error = ApiError::from_response(status.as_u16(), &method, &url, &body);
+                    error.retry_after = Some(wait);
+
+                    return Err(ProviderError::RateLimit(Box::new(error)));
+                }
+
+                tokio::time::sleep(wait).await;
+                rate_limit_wait += wait;
+                attempt += 1;
+                pending = retry;
+                continue;
+            }
+
+            if can_retry && idempotent && is_transient(status) {
+                tokio::time::sleep(self.retry.backoff(attempt)).await;
+                attempt += 1;
+                pending = retry;
+                continue;
+            }
+
+            let body = response.text().await.unwrap_or_default();
+            return Err(ProviderError::from_response(
+                status.as_u16(),
+                &method,
+                &url,
+                &body,
+            ));
+        }
+
+        unreachable!("the loop always returns before running out of requests")
+    }
+}
+
+/// Méthodes qu'il est sûr de rejouer : un second envoi ne crée rien de plus
+fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::PUT | Method::PATCH | Method::DELETE
+    )
+}
+
+fn is_transient(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::INTERNAL_SERVER_ERROR
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse().ok())
+}
+
+/// 429, ou 403 accompagné des en-têtes de limite de débit de GitHub
+/// (quota épuisé ou limite secondaire avec `Retry-After`)
+pub(crate) fn is_rate_limited(status: StatusCode, headers: &HeaderMap) -> bool {
+    match status {
+        StatusCode::TOO_MANY_REQUESTS => true,
+        StatusCode::FORBIDDEN => {
+            header_u64(headers, "x-ratelimit-remaining") == Some(0)
+                || headers.contains_key("retry-after")
+        }
+        _ => false,
+    }
+}
+
+/// Délai demandé par le serveur : `Retry-After` en secondes, sinon l'horodatage
+/// de réinitialisation `X-RateLimit-Reset` (GitHub) ou `RateLimit-Reset` (GitLab)
+pub(crate) fn rate_limit_delay(headers: &HeaderMap) -> Option<Duration> {
+    if let Some(seconds) = header_u64(headers, "retry-after") {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let reset = header_u64(headers, "x-ratelimit-reset")
+        .or_else(|| header_u64(headers, "ratelimit-reset"))?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+
+    // Une seconde de marge pour absorber le décalage d'horloge
+    Some(Duration::from_secs(reset.saturating_sub(now) + 1))
+}
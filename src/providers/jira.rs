@@ -0,0 +1,869 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use reqwest::{header, Client};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tokio::sync::Mutex;
+
+use crate::error::ProviderError;
+use crate::models::common::{
+    IssueCreate, IssueFilter, IssueState, Label, LinkKind, Milestone, ProjectIssue, RemoteIssue,
+    RemoteMilestone, RemoteRef,
+};
+use crate::providers::cache::MilestoneCache;
+use crate::providers::http::HttpClient;
+use crate::providers::ProviderConfig;
+use crate::traits::repository::RepositoryProvider;
+
+/// Type des issues créées
+const ISSUE_TYPE: &str = "Task";
+
+/// Type de lien utilisé pour les dépendances
+const BLOCKS: &str = "Blocks";
+const RELATES: &str = "Relates";
+
+const MAX_RESULTS: &str = "100";
+
+/// Champs lus lors des recherches JQL
+const ISSUE_FIELDS: &str = "summary,description,status,labels,fixVersions";
+
+/// Page des API à pagination par offset (`startAt` / `isLast`)
+#[derive(Debug, Deserialize)]
+struct JiraPage<T> {
+    values: Vec<T>,
+    #[serde(rename = "isLast", default = "default_true")]
+    is_last: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Page de `search/jql`, paginée par jeton
+#[derive(Debug, Deserialize)]
+struct JiraSearchPage {
+    issues: Vec<JiraIssue>,
+    #[serde(rename = "nextPageToken")]
+    next_page_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraVersion {
+    id: String,
+    name: String,
+    description: Option<String>,
+    #[serde(rename = "releaseDate")]
+    release_date: Option<String>,
+    #[serde(default)]
+    released: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraStatusCategory {
+    key: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraStatus {
+    #[serde(rename = "statusCategory")]
+    status_category: JiraStatusCategory,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraNamed {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraIssueFields {
+    summary: String,
+    description: Option<Value>,
+    status: JiraStatus,
+    #[serde(default)]
+    labels: Vec<String>,
+    #[serde(rename = "fixVersions", default)]
+    fix_versions: Vec<JiraNamed>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraIssue {
+    id: String,
+    key: String,
+    fields: JiraIssueFields,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraIssueRef {
+    id: String,
+    key: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraLinkedIssue {
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraIssueLink {
+    #[serde(rename = "type")]
+    link_type: JiraNamed,
+    #[serde(rename = "inwardIssue")]
+    inward_issue: Option<JiraLinkedIssue>,
+    #[serde(rename = "outwardIssue")]
+    outward_issue: Option<JiraLinkedIssue>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraLinksFields {
+    #[serde(default)]
+    issuelinks: Vec<JiraIssueLink>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraIssueLinks {
+    fields: JiraLinksFields,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraBoard {
+    id: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraSprint {
+    id: i64,
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraProject {
+    id: String,
+}
+
+fn parse_id(id: &str) -> Result<i64, ProviderError> {
+    id.parse()
+        .map_err(|_| ProviderError::Decode(format!("Invalid Jira id: {}", id)))
+}
+
+/// Échappe une valeur pour une requête JQL
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Jira refuse les espaces dans les labels
+fn jira_label(label: &str) -> String {
+    label.split_whitespace().collect::<Vec<_>>().join("_")
+}
+
+fn sprint_name(sprint: u32) -> String {
+    format!("Sprint {}", sprint)
+}
+
+fn text(content: &str) -> Value {
+    json!({ "type": "text", "text": content })
+}
+
+fn paragraph(content: &str) -> Value {
+    json!({ "type": "paragraph", "content": [text(content)] })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ListKind {
+    Bullet,
+    Ordered,
+    Tasks,
+}
+
+fn list_node(kind: ListKind, items: Vec<Value>, local_id: &mut usize) -> Value {
+    match kind {
+        ListKind::Bullet => json!({ "type": "bulletList", "content": items }),
+        ListKind::Ordered => json!({ "type": "orderedList", "content": items }),
+        ListKind::Tasks => {
+            *local_id += 1;
+            json!({
+                "type": "taskList",
+                "attrs": { "localId": format!("list-{}", local_id) },
+                "content": items,
+            })
+        }
+    }
+}
+
+/// Élément de liste porté par une ligne markdown, s'il y en a un
+fn list_item(line: &str, local_id: &mut usize) -> Option<(ListKind, Value)> {
+    let task = line
+        .strip_prefix("- [ ] ")
+        .map(|task| (task, "TODO"))
+        .or_else(|| {
+            line.strip_prefix("- [x] ")
+                .or_else(|| line.strip_prefix("- [X] "))
+                .map(|task| (task, "DONE"))
+        });
+    if let Some((task, state)) = task {
+        *local_id += 1;
+        return Some((
+            ListKind::Tasks,
+            json!({
+                "type": "taskItem",
+                "attrs": { "localId": format!("task-{}", local_id), "state": state },
+                "content": [text(task)],
+            }),
+        ));
+    }
+
+    if let Some(item) = line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) {
+        return Some((
+            ListKind::Bullet,
+            json!({ "type": "listItem", "content": [paragraph(item)] }),
+        ));
+    }
+
+    let (number, item) = line.split_once(". ")?;
+    if number.is_empty() || !number.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some((
+        ListKind::Ordered,
+        json!({ "type": "listItem", "content": [paragraph(item)] }),
+    ))
+}
+
+/// Convertit le markdown des descriptions (titres `#`, listes `-`, `1.`,
+/// cases à cocher `- [ ]` et paragraphes) en Atlassian Document Format
+fn to_adf(markdown: &str) -> Value {
+    let mut content = Vec::new();
+    // Les lignes de liste consécutives de même type forment une seule liste
+    let mut list: Option<(ListKind, Vec<Value>)> = None;
+    let mut local_id = 0;
+
+    for line in markdown.lines().map(str::trim) {
+        // Le marqueur HTML n'a pas d'équivalent ADF
+        if line.is_empty() || line.starts_with("<!--") {
+            continue;
+        }
+
+        if let Some((kind, item)) = list_item(line, &mut local_id) {
+            match &mut list {
+                Some((current, items)) if *current == kind => items.push(item),
+                _ => {
+                    if let Some((kind, items)) = list.replace((kind, vec![item])) {
+                        content.push(list_node(kind, items, &mut local_id));
+                    }
+                }
+            }
+            continue;
+        }
+
+        if let Some((kind, items)) = list.take() {
+            content.push(list_node(kind, items, &mut local_id));
+        }
+
+        let level = line.chars().take_while(|c| *c == '#').count();
+        if (1..=6).contains(&level) {
+            content.push(json!({
+                "type": "heading",
+                "attrs": { "level": level },
+                "content": [text(line[level..].trim())],
+            }));
+        } else {
+            content.push(paragraph(line));
+        }
+    }
+
+    if let Some((kind, items)) = list {
+        content.push(list_node(kind, items, &mut local_id));
+    }
+
+    json!({ "type": "doc", "version": 1, "content": content })
+}
+
+/// Texte brut d'un document ADF, un bloc par ligne
+fn adf_text(node: &Value) -> String {
+    fn collect(node: &Value, lines: &mut Vec<String>, line: &mut String) {
+        if let Some(text) = node.get("text").and_then(Value::as_str) {
+            line.push_str(text);
+        }
+
+        if let Some(children) = node.get("content").and_then(Value::as_array) {
+            for child in children {
+                collect(child, lines, line);
+            }
+        }
+
+        let is_block = matches!(
+            node.get("type").and_then(Value::as_str),
+            Some("paragraph" | "heading" | "taskItem" | "codeBlock")
+        );
+        if is_block && !line.is_empty() {
+            lines.push(std::mem::take(line));
+        }
+    }
+
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    collect(node, &mut lines, &mut line);
+    if !line.is_empty() {
+        lines.push(line);
+    }
+
+    lines.join("\n")
+}
+
+impl TryFrom<JiraVersion> for RemoteMilestone {
+    type Error = ProviderError;
+
+    fn try_from(version: JiraVersion) -> Result<Self, Self::Error> {
+        Ok(RemoteMilestone {
+            id: parse_id(&version.id)?,
+            title: version.name,
+            description: version.description.filter(|d| !d.is_empty()),
+            due_date: version.release_date,
+            state: if version.released {
+                IssueState::Closed
+            } else {
+                IssueState::Open
+            },
+        })
+    }
+}
+
+/// Provider pour Jira Cloud (API REST v3 et API Agile).
+///
+/// Les milestones sont des fix versions, les sprints du fichier projet des
+/// sprints du board Scrum du projet, et les dépendances des liens "Blocks".
+/// Le repository est la clé du projet, éventuellement suivie de l'identifiant
+/// du board (`PROJ/12`).
+pub struct JiraProvider {
+    client: HttpClient,
+    api_url: String,
+    project_key: String,
+    board_id: Option<i64>,
+    /// Board et sprints par nom, chargés à la première issue placée dans un sprint
+    sprints: Mutex<Option<(i64, HashMap<String, i64>)>>,
+    /// Identifiant numérique du projet, chargé au premier milestone
+    project_id: Mutex<Option<i64>>,
+    /// Fix versions par nom, chargées à la première issue rattachée par son nom
+    versions: MilestoneCache,
+}
+
+impl JiraProvider {
+    pub fn new(config: ProviderConfig) -> Result<Self, ProviderError> {
+        // Jira Cloud : `email:api_token` en Basic ; Jira Data Center : PAT en Bearer
+        let authorization = if config.token.contains(':') {
+            format!("Basic {}", STANDARD.encode(&config.token))
+        } else {
+            format!("Bearer {}", config.token)
+        };
+
+        let mut headers = header::HeaderMap::new();
+        headers.insert(
+            header::AUTHORIZATION,
+            header::HeaderValue::from_str(&authorization)
+                .map_err(|e| ProviderError::Config(format!("Invalid token: {}", e)))?,
+        );
+
+        let (project_key, board_id) = match config.repository.split_once('/') {
+            Some((key, board)) => {
+                let board = board.parse().map_err(|_| {
+                    ProviderError::Config(format!("Invalid Jira board id: {}", board))
+                })?;
+                (key.to_string(), Some(board))
+            }
+            None => (config.repository.clone(), None),
+        };
+
+        let client = Client::builder()
+            .default_headers(headers)
+            .build()
+            .map_err(|e| ProviderError::Network(e.to_string()))?;
+
+        Ok(Self {
+            client: HttpClient::new(client, config.retry),
+            api_url: config.api_url,
+            project_key,
+            board_id,
+            sprints: Mutex::new(None),
+            project_id: Mutex::new(None),
+            versions: MilestoneCache::default(),
+        })
+    }
+
+    fn rest_url(&self, path: &str) -> String {
+        format!("{}/rest/api/3/{}", self.api_url, path)
+    }
+
+    fn agile_url(&self, path: &str) -> String {
+        format!("{}/rest/agile/1.0/{}", self.api_url, path)
+    }
+
+    async fn get_json<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        query: &[(&str, String)],
+    ) -> Result<T, ProviderError> {
+        let response = self.client.send(self.client.get(url).query(query)).await?;
+
+        response
+            .json::<T>()
+            .await
+            .map_err(|e| ProviderError::Decode(format!("Failed to parse response: {}", e)))
+    }
+
+    async fn post_json<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        body: &Value,
+    ) -> Result<T, ProviderError> {
+        let response = self.client.send(self.client.post(url).json(body)).await?;
+
+        response
+            .json::<T>()
+            .await
+            .map_err(|e| ProviderError::Decode(format!("Failed to parse response: {}", e)))
+    }
+
+    /// Parcourt une API paginée par `startAt` jusqu'à la dernière page
+    async fn offset_pages<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        query: &[(&str, String)],
+    ) -> Result<Vec<T>, ProviderError> {
+        let mut values = Vec::new();
+
+        loop {
+            let mut page_query = query.to_vec();
+            page_query.push(("startAt", values.len().to_string()));
+            page_query.push(("maxResults", MAX_RESULTS.to_string()));
+
+            let page: JiraPage<T> = self.get_json(url, &page_query).await?;
+            let done = page.is_last || page.values.is_empty();
+            values.extend(page.values);
+
+            if done {
+                return Ok(values);
+            }
+        }
+    }
+
+    async fn search(&self, jql: &str) -> Result<Vec<JiraIssue>, ProviderError> {
+        let mut issues = Vec::new();
+        let mut token: Option<String> = None;
+
+        loop {
+            let mut query = vec![
+                ("jql", jql.to_string()),
+                ("fields", ISSUE_FIELDS.to_string()),
+                ("maxResults", MAX_RESULTS.to_string()),
+            ];
+            if let Some(token) = &token {
+                query.push(("nextPageToken", token.clone()));
+            }
+
+            let page: JiraSearchPage = self.get_json(&self.rest_url("search/jql"), &query).await?;
+            issues.extend(page.issues);
+
+            match page.next_page_token {
+                Some(next) => token = Some(next),
+                None => return Ok(issues),
+            }
+        }
+    }
+
+    fn remote_issue(&self, issue: JiraIssue) -> Result<RemoteIssue, ProviderError> {
+        let fields = issue.fields;
+
+        Ok(RemoteIssue {
+            id: parse_id(&issue.id)?,
+            title: fields.summary,
+            description: fields
+                .description
+                .as_ref()
+                .map(adf_text)
+                .unwrap_or_default(),
+            state: if fields.status.status_category.key == "done" {
+                IssueState::Closed
+            } else {
+                IssueState::Open
+            },
+            labels: fields.labels,
+            milestone: fields.fix_versions.into_iter().next().map(|v| v.name),
+            web_url: format!("{}/browse/{}", self.api_url, issue.key),
+        })
+    }
+
+    async fn board_id(&self) -> Result<i64, ProviderError> {
+        if let Some(board_id) = self.board_id {
+            return Ok(board_id);
+        }
+
+        let boards: Vec<JiraBoard> = self
+            .offset_pages(
+                &self.agile_url("board"),
+                &[
+                    ("projectKeyOrId", self.project_key.clone()),
+                    ("type", "scrum".to_string()),
+                ],
+            )
+            .await?;
+
+        boards.first().map(|board| board.id).ok_or_else(|| {
+            ProviderError::Config(format!(
+                "No Scrum board found for project {}, pass it as {}/<board id>",
+                self.project_key, self.project_key
+            ))
+        })
+    }
+
+    async fn project_id(&self) -> Result<i64, ProviderError> {
+        let mut project_id = self.project_id.lock().await;
+        if let Some(id) = *project_id {
+            return Ok(id);
+        }
+
+        let project: JiraProject = self
+            .get_json(
+                &self.rest_url(&format!("project/{}", self.project_key)),
+                &[],
+            )
+            .await?;
+        let id = parse_id(&project.id)?;
+        *project_id = Some(id);
+
+        Ok(id)
+    }
+
+    /// Identifiant de la fix version portant ce nom
+    async fn version_id(&self, name: &str) -> Result<i64, ProviderError> {
+        self.versions.require(self, name).await
+    }
+
+    /// Identifiant du sprint `sprint` du board, créé s'il n'existe pas encore
+    async fn sprint_id(&self, sprint: u32) -> Result<i64, ProviderError> {
+        let mut sprints = self.sprints.lock().await;
+
+        let (board_id, board_sprints) = match &mut *sprints {
+            Some(sprints) => sprints,
+            None => {
+                let board_id = self.board_id().await?;
+                let existing_sprints: Vec<JiraSprint> = self
+                    .offset_pages(&self.agile_url(&format!("board/{}/sprint", board_id)), &[])
+                    .await?;
+                sprints.insert((
+                    board_id,
+                    existing_sprints
+                        .into_iter()
+                        .map(|sprint| (sprint.name, sprint.id))
+                        .collect(),
+                ))
+            }
+        };
+
+        let name = sprint_name(sprint);
+        if let Some(id) = board_sprints.get(&name) {
+            return Ok(*id);
+        }
+
+        let created: JiraSprint = self
+            .post_json(
+                &self.agile_url("sprint"),
+                &json!({ "name": name, "originBoardId": board_id }),
+            )
+            .await?;
+        board_sprints.insert(name, created.id);
+
+        Ok(created.id)
+    }
+
+    async fn create_jira_issue(
+        &self,
+        title: &str,
+        markdown: &str,
+        labels: &[String],
+        fix_version: Option<i64>,
+        due_date: Option<&str>,
+    ) -> Result<JiraIssueRef, ProviderError> {
+        let mut fields = json!({
+            "project": { "key": self.project_key },
+            "summary": title,
+            "description": to_adf(markdown),
+            "issuetype": { "name": ISSUE_TYPE },
+            "labels": labels.iter().map(|label| jira_label(label)).collect::<Vec<_>>(),
+        });
+
+        if let Some(version) = fix_version {
+            fields["fixVersions"] = json!([{ "id": version.to_string() }]);
+        }
+
+        if let Some(due_date) = due_date {
+            fields["duedate"] = json!(due_date);
+        }
+
+        self.post_json(&self.rest_url("issue"), &json!({ "fields": fields }))
+            .await
+    }
+
+    /// Crée un lien `link_type` où `outward_id` est la source (`outward_id` bloque `inward_id`)
+    async fn add_link(
+        &self,
+        link_type: &str,
+        outward_id: i64,
+        inward_id: i64,
+    ) -> Result<(), ProviderError> {
+        let issue: JiraIssueLinks = self
+            .get_json(
+                &self.rest_url(&format!("issue/{}", inward_id)),
+                &[("fields", "issuelinks".to_string())],
+            )
+            .await?;
+
+        // Vu depuis `inward_id`, un lien "is blocked by" porte l'issue bloquante
+        // dans `inwardIssue` ; un lien "relates to" vaut dans les deux sens
+        let outward = outward_id.to_string();
+        let is_outward = |issue: &Option<JiraLinkedIssue>| {
+            issue.as_ref().map(|i| i.id.as_str()) == Some(outward.as_str())
+        };
+        let already_linked = issue.fields.issuelinks.iter().any(|link| {
+            link.link_type.name == link_type
+                && (is_outward(&link.inward_issue)
+                    || (link_type == RELATES && is_outward(&link.outward_issue)))
+        });
+        if already_linked {
+            return Ok(());
+        }
+
+        let link = json!({
+            "type": { "name": link_type },
+            "outwardIssue": { "id": outward },
+            "inwardIssue": { "id": inward_id.to_string() },
+        });
+
+        self.client
+            .send(self.client.post(self.rest_url("issueLink")).json(&link))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rattache l'issue `child_id` à son parent (epic ou issue de niveau supérieur)
+    async fn set_parent(&self, child_id: i64, parent_id: i64) -> Result<(), ProviderError> {
+        let fields = json!({ "fields": { "parent": { "id": parent_id.to_string() } } });
+
+        self.client
+            .send(
+                self.client
+                    .put(self.rest_url(&format!("issue/{}", child_id)))
+                    .json(&fields),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    fn issue_ref(&self, issue: &JiraIssueRef) -> Result<RemoteRef, ProviderError> {
+        Ok(RemoteRef::new(parse_id(&issue.id)?)
+            .with_web_url(format!("{}/browse/{}", self.api_url, issue.key)))
+    }
+}
+
+#[async_trait]
+impl RepositoryProvider for JiraProvider {
+    async fn create_label(&self, _label: &Label) -> Result<Option<RemoteRef>, ProviderError> {
+        // Les labels Jira existent dès qu'une issue les utilise
+        Ok(None)
+    }
+
+    async fn update_label(&self, _label: &Label) -> Result<(), ProviderError> {
+        // Jamais appelé par la synchronisation : voir `supports_label_colors`
+        Ok(())
+    }
+
+    fn supports_label_colors(&self) -> bool {
+        // Les labels Jira n'ont ni couleur ni description
+        false
+    }
+
+    fn label_name(&self, name: &str) -> String {
+        jira_label(name)
+    }
+
+    fn supports_label_deletion(&self) -> bool {
+        false
+    }
+
+    async fn delete_label(&self, name: &str) -> Result<(), ProviderError> {
+        Err(ProviderError::Config(format!(
+            "Jira labels cannot be deleted through the API: {}",
+            name
+        )))
+    }
+
+    async fn create_issue(&self, issue: &IssueCreate) -> Result<RemoteRef, ProviderError> {
+        // Jira Cloud n'assigne une issue que par identifiant de compte, et les
+        // story points sont un champ personnalisé propre à chaque instance
+        if !issue.assignees.is_empty() {
+            return Err(ProviderError::Config(format!(
+                "Jira cannot assign issues by username: {}",
+                issue.assignees.join(", ")
+            )));
+        }
+        if let Some(weight) = issue.weight {
+            return Err(ProviderError::Config(format!(
+                "Jira issues have no weight field: {}",
+                weight
+            )));
+        }
+
+        let fix_version = match (issue.milestone_id, &issue.milestone) {
+            (Some(id), _) => Some(id),
+            (None, Some(name)) => Some(self.version_id(name).await?),
+            (None, None) => None,
+        };
+
+        let created = self
+            .create_jira_issue(
+                &issue.title,
+                &issue.description,
+                &issue.labels,
+                fix_version,
+                issue.due_date.as_deref(),
+            )
+            .await?;
+
+        self.issue_ref(&created)
+    }
+
+    async fn create_milestone(&self, milestone: &Milestone) -> Result<RemoteRef, ProviderError> {
+        let project_id = self.project_id().await?;
+
+        let version: JiraVersion = self
+            .post_json(
+                &self.rest_url("version"),
+                &json!({
+                    "name": milestone.name,
+                    "description": milestone.description,
+                    "releaseDate": milestone.deadline,
+                    "projectId": project_id,
+                }),
+            )
+            .await?;
+        let id = parse_id(&version.id)?;
+
+        self.versions.insert(&milestone.name, id).await;
+
+        Ok(RemoteRef::new(id))
+    }
+
+    async fn create_issue_link(
+        &self,
+        from_id: i64,
+        to_id: i64,
+        kind: LinkKind,
+    ) -> Result<(), ProviderError> {
+        match kind {
+            LinkKind::BlockedBy => self.add_link(BLOCKS, to_id, from_id).await,
+            LinkKind::Blocks => self.add_link(BLOCKS, from_id, to_id).await,
+            LinkKind::RelatesTo => self.add_link(RELATES, to_id, from_id).await,
+            LinkKind::Parent => self.set_parent(to_id, from_id).await,
+            LinkKind::Child => self.set_parent(from_id, to_id).await,
+        }
+    }
+
+    async fn list_labels(&self) -> Result<Vec<Label>, ProviderError> {
+        let labels: Vec<String> = self.offset_pages(&self.rest_url("label"), &[]).await?;
+
+        Ok(labels
+            .into_iter()
+            .map(|name| Label {
+                name,
+                color: String::new(),
+                description: None,
+            })
+            .collect())
+    }
+
+    async fn list_milestones(&self) -> Result<Vec<RemoteMilestone>, ProviderError> {
+        let versions: Vec<JiraVersion> = self
+            .get_json(
+                &self.rest_url(&format!("project/{}/versions", self.project_key)),
+                &[],
+            )
+            .await?;
+
+        versions
+            .into_iter()
+            .map(RemoteMilestone::try_from)
+            .collect()
+    }
+
+    async fn list_issues(&self, filter: &IssueFilter) -> Result<Vec<RemoteIssue>, ProviderError> {
+        let mut clauses = vec![format!("project = {}", quote(&self.project_key))];
+
+        match filter.state {
+            Some(IssueState::Open) => clauses.push("statusCategory != Done".to_string()),
+            Some(IssueState::Closed) => clauses.push("statusCategory = Done".to_string()),
+            None => {}
+        }
+
+        for label in &filter.labels {
+            clauses.push(format!("labels = {}", quote(&jira_label(label))));
+        }
+
+        if let Some(milestone) = &filter.milestone {
+            clauses.push(format!("fixVersion = {}", quote(milestone)));
+        }
+
+        let jql = format!("{} ORDER BY created ASC", clauses.join(" AND "));
+
+        self.search(&jql)
+            .await?
+            .into_iter()
+            .map(|issue| self.remote_issue(issue))
+            .collect()
+    }
+
+    async fn get_issue(&self, id: i64) -> Result<RemoteIssue, ProviderError> {
+        let issue: JiraIssue = self
+            .get_json(
+                &self.rest_url(&format!("issue/{}", id)),
+                &[("fields", ISSUE_FIELDS.to_string())],
+            )
+            .await?;
+
+        self.remote_issue(issue)
+    }
+
+    async fn create_project_issue(
+        &self,
+        issue: &ProjectIssue,
+        _milestone: &Milestone,
+        milestone_id: i64,
+    ) -> Result<RemoteRef, ProviderError> {
+        // Résoudre le sprint avant de créer l'issue, pour ne pas laisser d'issue
+        // hors sprint si le board est introuvable
+        let sprint_id = self.sprint_id(issue.sprint).await?;
+
+        let created = self
+            .create_jira_issue(
+                &issue.title,
+                &issue.description.to_markdown(),
+                &issue.labels,
+                Some(milestone_id),
+                None,
+            )
+            .await?;
+
+        self.client
+            .send(
+                self.client
+                    .post(self.agile_url(&format!("sprint/{}/issue", sprint_id)))
+                    .json(&json!({ "issues": [created.key] })),
+            )
+            .await?;
+
+        self.issue_ref(&created)
+    }
+}
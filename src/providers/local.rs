@@ -0,0 +1,514 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::error::{ApiError, ProviderError};
+use crate::models::common::{
+    IssueCreate, IssueFilter, IssueState, Label, LinkKind, Milestone, ProjectIssue, RemoteIssue,
+    RemoteMilestone, RemoteRef,
+};
+use crate::traits::repository::RepositoryProvider;
+
+const LABELS_FILE: &str = "labels.json";
+const MILESTONES_FILE: &str = "milestones.json";
+const ISSUES_DIR: &str = "issues";
+
+const FRONT_MATTER_DELIMITER: &str = "---";
+
+/// Issue telle qu'enregistrée dans un fichier Markdown
+#[derive(Debug, Clone, PartialEq)]
+struct LocalIssue {
+    id: i64,
+    title: String,
+    state: IssueState,
+    labels: Vec<String>,
+    milestone: Option<String>,
+    estimate: Option<String>,
+    sprint: Option<u32>,
+    assignees: Vec<String>,
+    due_date: Option<String>,
+    weight: Option<u32>,
+    depends_on: Vec<i64>,
+    related_to: Vec<i64>,
+    parent: Option<i64>,
+    body: String,
+}
+
+/// Chaîne YAML entre guillemets ; une chaîne JSON est un scalaire YAML valide
+fn yaml_string(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
+
+fn parse_yaml_string(value: &str) -> Result<String, String> {
+    if value.starts_with('"') {
+        serde_json::from_str(value).map_err(|e| e.to_string())
+    } else {
+        Ok(value.to_string())
+    }
+}
+
+impl LocalIssue {
+    /// Issue ouverte sans métadonnées, dont l'identifiant reste à attribuer
+    fn open(title: &str, body: &str) -> Self {
+        LocalIssue {
+            id: 0,
+            title: title.to_string(),
+            state: IssueState::Open,
+            labels: Vec::new(),
+            milestone: None,
+            estimate: None,
+            sprint: None,
+            assignees: Vec::new(),
+            due_date: None,
+            weight: None,
+            depends_on: Vec::new(),
+            related_to: Vec::new(),
+            parent: None,
+            body: body.trim_end().to_string(),
+        }
+    }
+
+    fn to_markdown(&self) -> String {
+        let mut lines = vec![
+            FRONT_MATTER_DELIMITER.to_string(),
+            format!("id: {}", self.id),
+            format!("title: {}", yaml_string(&self.title)),
+            format!(
+                "state: {}",
+                match self.state {
+                    IssueState::Open => "open",
+                    IssueState::Closed => "closed",
+                }
+            ),
+        ];
+
+        if let Some(milestone) = &self.milestone {
+            lines.push(format!("milestone: {}", yaml_string(milestone)));
+        }
+        if let Some(estimate) = &self.estimate {
+            lines.push(format!("estimate: {}", yaml_string(estimate)));
+        }
+        if let Some(sprint) = self.sprint {
+            lines.push(format!("sprint: {}", sprint));
+        }
+        if let Some(due_date) = &self.due_date {
+            lines.push(format!("due_date: {}", yaml_string(due_date)));
+        }
+        if let Some(weight) = self.weight {
+            lines.push(format!("weight: {}", weight));
+        }
+        if let Some(parent) = self.parent {
+            lines.push(format!("parent: {}", parent));
+        }
+
+        lines.push("labels:".to_string());
+        lines.extend(
+            self.labels
+                .iter()
+                .map(|label| format!("  - {}", yaml_string(label))),
+        );
+
+        lines.push("depends_on:".to_string());
+        lines.extend(self.depends_on.iter().map(|id| format!("  - {}", id)));
+
+        if !self.related_to.is_empty() {
+            lines.push("related_to:".to_string());
+            lines.extend(self.related_to.iter().map(|id| format!("  - {}", id)));
+        }
+
+        if !self.assignees.is_empty() {
+            lines.push("assignees:".to_string());
+            lines.extend(
+                self.assignees
+                    .iter()
+                    .map(|assignee| format!("  - {}", yaml_string(assignee))),
+            );
+        }
+
+        lines.push(FRONT_MATTER_DELIMITER.to_string());
+        lines.push(String::new());
+        lines.push(self.body.clone());
+
+        let mut markdown = lines.join("\n");
+        if !markdown.ends_with('\n') {
+            markdown.push('\n');
+        }
+        markdown
+    }
+
+    /// Relit un fichier écrit par [`LocalIssue::to_markdown`]
+    fn parse(content: &str) -> Result<Self, String> {
+        let rest = content
+            .strip_prefix(FRONT_MATTER_DELIMITER)
+            .and_then(|rest| rest.strip_prefix('\n'))
+            .ok_or("missing front matter")?;
+        let (front_matter, body) = rest
+            .split_once("\n---\n")
+            .ok_or("unterminated front matter")?;
+
+        let mut issue = LocalIssue::open("", body.strip_prefix('\n').unwrap_or(body));
+        let mut list: Option<&str> = None;
+
+        for line in front_matter.lines() {
+            if let Some(item) = line.trim_start().strip_prefix("- ") {
+                match list {
+                    Some("labels") => issue.labels.push(parse_yaml_string(item.trim())?),
+                    Some("depends_on") => issue
+                        .depends_on
+                        .push(item.trim().parse().map_err(|_| "invalid dependency id")?),
+                    Some("related_to") => issue
+                        .related_to
+                        .push(item.trim().parse().map_err(|_| "invalid related id")?),
+                    Some("assignees") => issue.assignees.push(parse_yaml_string(item.trim())?),
+                    _ => return Err(format!("unexpected list item: {}", line)),
+                }
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once(':')
+                .ok_or_else(|| format!("invalid line: {}", line))?;
+            let value = value.trim();
+            list = None;
+
+            match key {
+                "id" => issue.id = value.parse().map_err(|_| "invalid id")?,
+                "title" => issue.title = parse_yaml_string(value)?,
+                "state" => {
+                    issue.state = match value {
+                        "closed" => IssueState::Closed,
+                        _ => IssueState::Open,
+                    }
+                }
+                "milestone" => issue.milestone = Some(parse_yaml_string(value)?),
+                "estimate" => issue.estimate = Some(parse_yaml_string(value)?),
+                "sprint" => issue.sprint = Some(value.parse().map_err(|_| "invalid sprint")?),
+                "due_date" => issue.due_date = Some(parse_yaml_string(value)?),
+                "weight" => issue.weight = Some(value.parse().map_err(|_| "invalid weight")?),
+                "parent" => issue.parent = Some(value.parse().map_err(|_| "invalid parent id")?),
+                "labels" | "depends_on" | "related_to" | "assignees" => list = Some(key),
+                // Champs ajoutés à la main : ignorés
+                _ => {}
+            }
+        }
+
+        Ok(issue)
+    }
+
+    fn to_remote(&self, path: &Path) -> RemoteIssue {
+        RemoteIssue {
+            id: self.id,
+            title: self.title.clone(),
+            description: self.body.clone(),
+            state: self.state,
+            labels: self.labels.clone(),
+            milestone: self.milestone.clone(),
+            web_url: path.display().to_string(),
+        }
+    }
+}
+
+/// Nom de fichier lisible dérivé du titre
+fn slug(title: &str) -> String {
+    let slug: String = title
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect();
+
+    slug.split('-')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+        .chars()
+        .take(50)
+        .collect()
+}
+
+/// Provider hors ligne qui écrit le projet dans une arborescence locale :
+///
+/// ```text
+/// <racine>/
+///   labels.json
+///   milestones.json
+///   issues/0001-setup-ci.md
+/// ```
+///
+/// Chaque issue est un fichier Markdown avec un front matter YAML. Les
+/// identifiants sont séquentiels, ce qui rend le résultat déterministe et
+/// versionnable.
+pub struct LocalProvider {
+    root: PathBuf,
+}
+
+impl LocalProvider {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Erreur `NotFound`, comme une forge répondant 404 sur le fichier concerné
+    fn not_found(&self, method: &str, name: &str, message: String) -> ProviderError {
+        ProviderError::NotFound(Box::new(ApiError::new(
+            404,
+            method,
+            self.root.join(name).display().to_string(),
+            message,
+        )))
+    }
+
+    fn read_json<T: DeserializeOwned + Default>(&self, name: &str) -> Result<T, ProviderError> {
+        match fs::read_to_string(self.root.join(name)) {
+            Ok(content) => Ok(serde_json::from_str(&content)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(T::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn write_json<T: Serialize>(&self, name: &str, value: &T) -> Result<(), ProviderError> {
+        fs::create_dir_all(&self.root)?;
+        let mut content = serde_json::to_string_pretty(value)?;
+        content.push('\n');
+        fs::write(self.root.join(name), content)?;
+        Ok(())
+    }
+
+    fn labels(&self) -> Result<Vec<Label>, ProviderError> {
+        self.read_json(LABELS_FILE)
+    }
+
+    fn milestones(&self) -> Result<Vec<RemoteMilestone>, ProviderError> {
+        self.read_json(MILESTONES_FILE)
+    }
+
+    /// Issues enregistrées, triées par identifiant
+    fn issues(&self) -> Result<Vec<(PathBuf, LocalIssue)>, ProviderError> {
+        let dir = self.root.join(ISSUES_DIR);
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut issues = Vec::new();
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("md") {
+                continue;
+            }
+
+            let content = fs::read_to_string(&path)?;
+            let issue = LocalIssue::parse(&content).map_err(|e| {
+                ProviderError::Decode(format!("Invalid issue file {}: {}", path.display(), e))
+            })?;
+            issues.push((path, issue));
+        }
+
+        issues.sort_by_key(|(_, issue)| issue.id);
+        Ok(issues)
+    }
+
+    fn issue(&self, id: i64) -> Result<(PathBuf, LocalIssue), ProviderError> {
+        self.issues()?
+            .into_iter()
+            .find(|(_, issue)| issue.id == id)
+            .ok_or_else(|| self.not_found("GET", ISSUES_DIR, format!("Issue not found: {}", id)))
+    }
+
+    fn write_issue(&self, path: &Path, issue: &LocalIssue) -> Result<(), ProviderError> {
+        fs::write(path, issue.to_markdown())?;
+        Ok(())
+    }
+
+    /// Enregistre `issue` sous le prochain identifiant libre
+    fn add_issue(&self, mut issue: LocalIssue) -> Result<RemoteRef, ProviderError> {
+        let id = self
+            .issues()?
+            .last()
+            .map(|(_, issue)| issue.id + 1)
+            .unwrap_or(1);
+        issue.id = id;
+
+        let dir = self.root.join(ISSUES_DIR);
+        fs::create_dir_all(&dir)?;
+        let path = dir.join(format!("{:04}-{}.md", id, slug(&issue.title)));
+        self.write_issue(&path, &issue)?;
+
+        Ok(RemoteRef::new(id).with_web_url(path.display().to_string()))
+    }
+}
+
+#[async_trait]
+impl RepositoryProvider for LocalProvider {
+    async fn create_label(&self, label: &Label) -> Result<Option<RemoteRef>, ProviderError> {
+        let mut labels = self.labels()?;
+        if labels
+            .iter()
+            .any(|existing| existing.name.eq_ignore_ascii_case(&label.name))
+        {
+            return Err(ProviderError::Config(format!(
+                "Label already exists: {}",
+                label.name
+            )));
+        }
+
+        labels.push(label.clone());
+        self.write_json(LABELS_FILE, &labels)?;
+
+        // Les labels locaux ne sont identifiés que par leur nom
+        Ok(None)
+    }
+
+    async fn update_label(&self, label: &Label) -> Result<(), ProviderError> {
+        let mut labels = self.labels()?;
+        let existing = labels
+            .iter_mut()
+            .find(|existing| existing.name.eq_ignore_ascii_case(&label.name))
+            .ok_or_else(|| {
+                self.not_found(
+                    "PUT",
+                    LABELS_FILE,
+                    format!("Label not found: {}", label.name),
+                )
+            })?;
+
+        *existing = label.clone();
+        self.write_json(LABELS_FILE, &labels)
+    }
+
+    async fn delete_label(&self, name: &str) -> Result<(), ProviderError> {
+        let mut labels = self.labels()?;
+        let count = labels.len();
+        labels.retain(|label| !label.name.eq_ignore_ascii_case(name));
+
+        if labels.len() == count {
+            return Err(self.not_found(
+                "DELETE",
+                LABELS_FILE,
+                format!("Label not found: {}", name),
+            ));
+        }
+
+        self.write_json(LABELS_FILE, &labels)
+    }
+
+    async fn create_issue(&self, issue: &IssueCreate) -> Result<RemoteRef, ProviderError> {
+        // Le milestone, les personnes assignées, l'échéance et le poids sont
+        // conservés dans le front matter
+        self.add_issue(LocalIssue {
+            labels: issue.labels.clone(),
+            milestone: issue.milestone.clone(),
+            assignees: issue.assignees.clone(),
+            due_date: issue.due_date.clone(),
+            weight: issue.weight,
+            ..LocalIssue::open(&issue.title, &issue.description)
+        })
+    }
+
+    async fn create_milestone(&self, milestone: &Milestone) -> Result<RemoteRef, ProviderError> {
+        let mut milestones = self.milestones()?;
+        let id = milestones.iter().map(|m| m.id).max().unwrap_or(0) + 1;
+
+        milestones.push(RemoteMilestone {
+            id,
+            title: milestone.name.clone(),
+            description: Some(milestone.description.clone()),
+            due_date: Some(milestone.deadline.clone()),
+            state: IssueState::Open,
+        });
+        self.write_json(MILESTONES_FILE, &milestones)?;
+
+        Ok(RemoteRef::new(id))
+    }
+
+    async fn create_issue_link(
+        &self,
+        from_id: i64,
+        to_id: i64,
+        kind: LinkKind,
+    ) -> Result<(), ProviderError> {
+        // Vérifie que la cible existe avant d'écrire le lien
+        self.issue(to_id)?;
+
+        // Le lien est écrit dans l'issue qui dépend de l'autre, ou dans l'enfant
+        let (issue_id, target_id) = match kind {
+            LinkKind::Blocks | LinkKind::Parent => (to_id, from_id),
+            LinkKind::BlockedBy | LinkKind::RelatesTo | LinkKind::Child => (from_id, to_id),
+        };
+
+        let (path, mut issue) = self.issue(issue_id)?;
+        let list = match kind {
+            LinkKind::Blocks | LinkKind::BlockedBy => Some(&mut issue.depends_on),
+            LinkKind::RelatesTo => Some(&mut issue.related_to),
+            LinkKind::Parent | LinkKind::Child => None,
+        };
+
+        let changed = match list {
+            Some(list) if !list.contains(&target_id) => {
+                list.push(target_id);
+                true
+            }
+            Some(_) => false,
+            None => issue.parent.replace(target_id) != Some(target_id),
+        };
+        if changed {
+            self.write_issue(&path, &issue)?;
+        }
+
+        Ok(())
+    }
+
+    async fn list_labels(&self) -> Result<Vec<Label>, ProviderError> {
+        self.labels()
+    }
+
+    async fn list_milestones(&self) -> Result<Vec<RemoteMilestone>, ProviderError> {
+        self.milestones()
+    }
+
+    async fn list_issues(&self, filter: &IssueFilter) -> Result<Vec<RemoteIssue>, ProviderError> {
+        Ok(self
+            .issues()?
+            .into_iter()
+            .filter(|(_, issue)| filter.state.is_none_or(|state| issue.state == state))
+            .filter(|(_, issue)| {
+                filter.labels.iter().all(|wanted| {
+                    issue
+                        .labels
+                        .iter()
+                        .any(|label| label.eq_ignore_ascii_case(wanted))
+                })
+            })
+            .filter(|(_, issue)| {
+                filter
+                    .milestone
+                    .as_ref()
+                    .is_none_or(|milestone| issue.milestone.as_ref() == Some(milestone))
+            })
+            .map(|(path, issue)| issue.to_remote(&path))
+            .collect())
+    }
+
+    async fn get_issue(&self, id: i64) -> Result<RemoteIssue, ProviderError> {
+        let (path, issue) = self.issue(id)?;
+        Ok(issue.to_remote(&path))
+    }
+
+    async fn create_project_issue(
+        &self,
+        issue: &ProjectIssue,
+        milestone: &Milestone,
+        _milestone_id: i64,
+    ) -> Result<RemoteRef, ProviderError> {
+        // Le milestone, l'estimation et le sprint sont conservés dans le front matter
+        self.add_issue(LocalIssue {
+            labels: issue.labels.clone(),
+            milestone: Some(milestone.name.clone()),
+            estimate: Some(issue.estimate.clone()).filter(|estimate| !estimate.is_empty()),
+            sprint: Some(issue.sprint),
+            ..LocalIssue::open(&issue.title, &issue.to_markdown())
+        })
+    }
+}
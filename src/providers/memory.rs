@@ -0,0 +1,323 @@
+use std::collections::BTreeSet;
+use std::sync::{Mutex, MutexGuard};
+
+use async_trait::async_trait;
+
+use crate::error::{ApiError, ProviderError};
+use crate::models::common::{
+    IssueCreate, IssueFilter, IssueState, Label, LinkKind, Milestone, RemoteIssue, RemoteMilestone,
+    RemoteRef,
+};
+use crate::traits::repository::RepositoryProvider;
+
+const URL: &str = "memory://";
+
+/// Appel reçu par un [`InMemoryProvider`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Operation {
+    CreateLabel(String),
+    UpdateLabel(String),
+    DeleteLabel(String),
+    /// Titre de l'issue
+    CreateIssue(String),
+    /// Nom du milestone
+    CreateMilestone(String),
+    CreateIssueLink {
+        from: i64,
+        to: i64,
+        kind: LinkKind,
+    },
+    ListLabels,
+    ListMilestones,
+    ListIssues,
+    GetIssue(i64),
+}
+
+#[derive(Debug, Default)]
+struct Store {
+    labels: Vec<Label>,
+    milestones: Vec<RemoteMilestone>,
+    issues: Vec<RemoteIssue>,
+    links: Vec<(i64, i64, LinkKind)>,
+    operations: Vec<Operation>,
+    /// Nombre d'appels reçus, y compris ceux retirés du journal
+    calls: usize,
+    /// Numéros d'appel (à partir de 1) qui doivent échouer
+    failures: BTreeSet<usize>,
+}
+
+fn not_found(method: &str, path: &str, message: String) -> ProviderError {
+    ProviderError::NotFound(Box::new(ApiError::new(
+        404,
+        method,
+        format!("{}{}", URL, path),
+        message,
+    )))
+}
+
+/// Provider en mémoire pour tester l'orchestration sans réseau.
+///
+/// Les entités sont conservées en mémoire avec des identifiants séquentiels,
+/// chaque appel est journalisé dans l'ordre et des échecs peuvent être
+/// programmés sur des appels donnés, ce qui permet de vérifier la reprise
+/// d'un `setup` interrompu.
+#[derive(Debug, Default)]
+pub struct InMemoryProvider {
+    store: Mutex<Store>,
+}
+
+impl InMemoryProvider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Labels présents avant le premier appel
+    pub fn with_labels(self, labels: Vec<Label>) -> Self {
+        self.lock().labels = labels;
+        self
+    }
+
+    /// Fait échouer le `n`-ième appel, en comptant à partir de 1 tous les appels
+    /// reçus. L'appel en échec est journalisé mais n'a aucun effet.
+    pub fn fail_on_call(self, n: usize) -> Self {
+        self.lock().failures.insert(n);
+        self
+    }
+
+    /// Appels reçus, dans l'ordre
+    pub fn operations(&self) -> Vec<Operation> {
+        self.lock().operations.clone()
+    }
+
+    /// Vide le journal des appels, par exemple après la mise en place d'un test.
+    /// Les échecs programmés restent comptés depuis le premier appel.
+    pub fn clear_operations(&self) {
+        self.lock().operations.clear();
+    }
+
+    pub fn labels(&self) -> Vec<Label> {
+        self.lock().labels.clone()
+    }
+
+    pub fn milestones(&self) -> Vec<RemoteMilestone> {
+        self.lock().milestones.clone()
+    }
+
+    pub fn issues(&self) -> Vec<RemoteIssue> {
+        self.lock().issues.clone()
+    }
+
+    /// Liens créés, sous la forme `(from_id, to_id, kind)`
+    pub fn links(&self) -> Vec<(i64, i64, LinkKind)> {
+        self.lock().links.clone()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Store> {
+        // Un test qui panique en tenant le verrou ne doit pas masquer son erreur
+        self.store
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Journalise `operation` puis l'exécute, sauf si un échec est programmé
+    fn call<T>(
+        &self,
+        operation: Operation,
+        f: impl FnOnce(&mut Store) -> Result<T, ProviderError>,
+    ) -> Result<T, ProviderError> {
+        let mut store = self.lock();
+        store.calls += 1;
+        store.operations.push(operation.clone());
+
+        if store.failures.contains(&store.calls) {
+            return Err(ProviderError::Network(format!(
+                "Scripted failure on call {}: {:?}",
+                store.calls, operation
+            )));
+        }
+
+        f(&mut store)
+    }
+
+    fn add_issue(&self, issue: &IssueCreate) -> Result<RemoteRef, ProviderError> {
+        self.call(Operation::CreateIssue(issue.title.clone()), |store| {
+            let id = store.issues.iter().map(|i| i.id).max().unwrap_or(0) + 1;
+            let web_url = format!("{}issues/{}", URL, id);
+
+            store.issues.push(RemoteIssue {
+                id,
+                title: issue.title.clone(),
+                description: issue.description.clone(),
+                state: IssueState::Open,
+                labels: issue.labels.clone(),
+                milestone: issue.milestone.clone(),
+                web_url: web_url.clone(),
+            });
+
+            Ok(RemoteRef::new(id).with_web_url(web_url))
+        })
+    }
+}
+
+#[async_trait]
+impl RepositoryProvider for InMemoryProvider {
+    async fn create_label(&self, label: &Label) -> Result<Option<RemoteRef>, ProviderError> {
+        self.call(Operation::CreateLabel(label.name.clone()), |store| {
+            if store
+                .labels
+                .iter()
+                .any(|existing| existing.name.eq_ignore_ascii_case(&label.name))
+            {
+                return Err(ProviderError::Validation(Box::new(ApiError::new(
+                    422,
+                    "POST",
+                    format!("{}labels", URL),
+                    format!("Label already exists: {}", label.name),
+                ))));
+            }
+
+            store.labels.push(label.clone());
+            Ok(None)
+        })
+    }
+
+    async fn update_label(&self, label: &Label) -> Result<(), ProviderError> {
+        self.call(Operation::UpdateLabel(label.name.clone()), |store| {
+            let existing = store
+                .labels
+                .iter_mut()
+                .find(|existing| existing.name.eq_ignore_ascii_case(&label.name))
+                .ok_or_else(|| {
+                    not_found("PUT", "labels", format!("Label not found: {}", label.name))
+                })?;
+
+            *existing = label.clone();
+            Ok(())
+        })
+    }
+
+    async fn delete_label(&self, name: &str) -> Result<(), ProviderError> {
+        self.call(Operation::DeleteLabel(name.to_string()), |store| {
+            let count = store.labels.len();
+            store
+                .labels
+                .retain(|label| !label.name.eq_ignore_ascii_case(name));
+
+            if store.labels.len() == count {
+                return Err(not_found(
+                    "DELETE",
+                    "labels",
+                    format!("Label not found: {}", name),
+                ));
+            }
+
+            Ok(())
+        })
+    }
+
+    async fn create_issue(&self, issue: &IssueCreate) -> Result<RemoteRef, ProviderError> {
+        self.add_issue(issue)
+    }
+
+    async fn create_milestone(&self, milestone: &Milestone) -> Result<RemoteRef, ProviderError> {
+        self.call(
+            Operation::CreateMilestone(milestone.name.clone()),
+            |store| {
+                let id = store.milestones.iter().map(|m| m.id).max().unwrap_or(0) + 1;
+
+                store.milestones.push(RemoteMilestone {
+                    id,
+                    title: milestone.name.clone(),
+                    description: Some(milestone.description.clone()),
+                    due_date: Some(milestone.deadline.clone()),
+                    state: IssueState::Open,
+                });
+
+                Ok(RemoteRef::new(id))
+            },
+        )
+    }
+
+    async fn create_issue_link(
+        &self,
+        from_id: i64,
+        to_id: i64,
+        kind: LinkKind,
+    ) -> Result<(), ProviderError> {
+        let operation = Operation::CreateIssueLink {
+            from: from_id,
+            to: to_id,
+            kind,
+        };
+
+        self.call(operation, |store| {
+            for id in [from_id, to_id] {
+                if !store.issues.iter().any(|issue| issue.id == id) {
+                    return Err(not_found(
+                        "POST",
+                        "links",
+                        format!("Issue not found: {}", id),
+                    ));
+                }
+            }
+
+            if !store.links.contains(&(from_id, to_id, kind)) {
+                store.links.push((from_id, to_id, kind));
+            }
+
+            Ok(())
+        })
+    }
+
+    async fn list_labels(&self) -> Result<Vec<Label>, ProviderError> {
+        self.call(Operation::ListLabels, |store| Ok(store.labels.clone()))
+    }
+
+    async fn list_milestones(&self) -> Result<Vec<RemoteMilestone>, ProviderError> {
+        self.call(Operation::ListMilestones, |store| {
+            Ok(store.milestones.clone())
+        })
+    }
+
+    async fn list_issues(&self, filter: &IssueFilter) -> Result<Vec<RemoteIssue>, ProviderError> {
+        self.call(Operation::ListIssues, |store| {
+            Ok(store
+                .issues
+                .iter()
+                .filter(|issue| filter.state.is_none_or(|state| issue.state == state))
+                .filter(|issue| {
+                    filter.labels.iter().all(|wanted| {
+                        issue
+                            .labels
+                            .iter()
+                            .any(|label| label.eq_ignore_ascii_case(wanted))
+                    })
+                })
+                .filter(|issue| {
+                    filter
+                        .milestone
+                        .as_ref()
+                        .is_none_or(|milestone| issue.milestone.as_ref() == Some(milestone))
+                })
+                .cloned()
+                .collect())
+        })
+    }
+
+    async fn get_issue(&self, id: i64) -> Result<RemoteIssue, ProviderError> {
+        self.call(Operation::GetIssue(id), |store| {
+            store
+                .issues
+                .iter()
+                .find(|issue| issue.id == id)
+                .cloned()
+                .ok_or_else(|| {
+                    not_found(
+                        "GET",
+                        &format!("issues/{}", id),
+                        format!("Issue not found: {}", id),
+                    )
+                })
+        })
+    }
+}
@@ -1,8 +1,25 @@
+mod azure;
+mod bitbucket;
+mod cache;
+pub mod existing;
+mod gitea;
 mod github;
 mod gitlab;
+pub mod http;
+mod jira;
+mod local;
+mod memory;
+pub mod pagination;
 
+pub use self::azure::AzureDevOpsProvider;
+pub use self::bitbucket::BitbucketProvider;
+pub use self::gitea::GiteaProvider;
 pub use self::github::GitHubProvider;
 pub use self::gitlab::GitLabProvider;
+pub use self::http::RetryPolicy;
+pub use self::jira::JiraProvider;
+pub use self::local::LocalProvider;
+pub use self::memory::{InMemoryProvider, Operation};
 
 use crate::error::ProviderError;
 use crate::traits::repository::DynProvider;
@@ -11,13 +28,101 @@ use crate::traits::repository::DynProvider;
 pub enum ProviderType {
     GitHub,
     GitLab,
+    /// Gitea et Forgejo, qui partagent la même API
+    Gitea,
+    /// Issue tracker de Bitbucket Cloud
+    Bitbucket,
+    /// Azure DevOps Boards
+    AzureDevOps,
+    /// Jira Cloud ou Data Center
+    Jira,
+    /// Arborescence locale de fichiers Markdown, sans forge
+    Local,
 }
 
+impl ProviderType {
+    /// Nom court du provider, tel qu'utilisé en ligne de commande
+    pub fn name(&self) -> &'static str {
+        match self {
+            ProviderType::GitHub => "github",
+            ProviderType::GitLab => "gitlab",
+            ProviderType::Gitea => "gitea",
+            ProviderType::Bitbucket => "bitbucket",
+            ProviderType::AzureDevOps => "azure-devops",
+            ProviderType::Jira => "jira",
+            ProviderType::Local => "local",
+        }
+    }
+}
+
+/// Paramètres de connexion d'un provider.
+///
+/// Les champs restent lisibles, mais la configuration se construit avec
+/// [`ProviderConfig::builder`] : de nouveaux champs peuvent être ajoutés sans
+/// casser le code appelant.
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct ProviderConfig {
     pub api_url: String,
     pub token: String,
     pub repository: String,
+    pub retry: RetryPolicy,
+}
+
+impl ProviderConfig {
+    pub fn builder() -> ProviderConfigBuilder {
+        ProviderConfigBuilder::default()
+    }
+}
+
+/// Construit une [`ProviderConfig`] en vérifiant que tous les champs sont renseignés
+#[derive(Debug, Default, Clone)]
+pub struct ProviderConfigBuilder {
+    api_url: Option<String>,
+    token: Option<String>,
+    repository: Option<String>,
+    retry: Option<RetryPolicy>,
+}
+
+impl ProviderConfigBuilder {
+    pub fn api_url(mut self, api_url: impl Into<String>) -> Self {
+        self.api_url = Some(api_url.into());
+        self
+    }
+
+    pub fn token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    pub fn repository(mut self, repository: impl Into<String>) -> Self {
+        self.repository = Some(repository.into());
+        self
+    }
+
+    pub fn retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    pub fn build(self) -> Result<ProviderConfig, ProviderError> {
+        fn required(value: Option<String>, field: &str) -> Result<String, ProviderError> {
+            match value {
+                Some(value) if !value.trim().is_empty() => Ok(value),
+                _ => Err(ProviderError::Config(format!("Missing {}", field))),
+            }
+        }
+
+        Ok(ProviderConfig {
+            // Évite les doubles slashes lors de la construction des URLs
+            api_url: required(self.api_url, "api_url")?
+                .trim_end_matches('/')
+                .to_string(),
+            token: required(self.token, "token")?,
+            repository: required(self.repository, "repository")?,
+            retry: self.retry.unwrap_or_default(),
+        })
+    }
 }
 
 pub fn create_provider(
@@ -41,5 +146,39 @@ pub fn create_provider(
             let provider = GitLabProvider::new(config)?;
             Ok(Box::new(provider))
         }
+        ProviderType::Gitea => {
+            println!(
+                "Creating Gitea provider for repository: {}",
+                config.repository
+            );
+            let provider = GiteaProvider::new(config)?;
+            Ok(Box::new(provider))
+        }
+        ProviderType::Bitbucket => {
+            println!(
+                "Creating Bitbucket provider for repository: {}",
+                config.repository
+            );
+            let provider = BitbucketProvider::new(config)?;
+            Ok(Box::new(provider))
+        }
+        ProviderType::AzureDevOps => {
+            println!(
+                "Creating Azure DevOps provider for project: {}",
+                config.repository
+            );
+            let provider = AzureDevOpsProvider::new(config)?;
+            Ok(Box::new(provider))
+        }
+        ProviderType::Jira => {
+            println!("Creating Jira provider for project: {}", config.repository);
+            let provider = JiraProvider::new(config)?;
+            Ok(Box::new(provider))
+        }
+        ProviderType::Local => {
+            // Le repository est le répertoire cible ; URL et token sont ignorés
+            println!("Creating local provider in: {}", config.repository);
+            Ok(Box::new(LocalProvider::new(config.repository)))
+        }
     }
 }
@@ -0,0 +1,162 @@
+use futures::stream::{self, Stream, TryStreamExt};
+use reqwest::header::HeaderMap;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use crate::error::ProviderError;
+use crate::providers::http::HttpClient;
+
+/// Nombre maximum d'éléments par page accepté par GitHub et GitLab
+pub const PER_PAGE: &str = "100";
+
+/// Mécanisme utilisé par le provider pour indiquer la page suivante
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pagination {
+    /// En-tête `Link: <url>; rel="next"` (GitHub)
+    LinkHeader,
+    /// En-têtes `X-Next-Page` / `X-Page` / `X-Total-Pages` (GitLab)
+    PageHeaders,
+    /// Corps `{"values": [...], "next": "url"}` (Bitbucket)
+    NextField,
+}
+
+#[derive(Deserialize)]
+struct ValuesPage<T> {
+    values: Vec<T>,
+    next: Option<String>,
+}
+
+struct PageRequest {
+    url: String,
+    query: Vec<(&'static str, String)>,
+}
+
+/// Parcourt toutes les pages d'un endpoint de liste et retourne les éléments
+/// sous forme de stream, en suivant le mécanisme de pagination du provider.
+pub fn paginate<T>(
+    client: HttpClient,
+    url: String,
+    mut query: Vec<(&'static str, String)>,
+    pagination: Pagination,
+) -> impl Stream<Item = Result<T, ProviderError>> + Send
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    if !query.iter().any(|(key, _)| *key == "per_page") {
+        query.push(("per_page", PER_PAGE.to_string()));
+    }
+
+    stream::try_unfold(Some(PageRequest { url, query }), move |next| {
+        let client = client.clone();
+        async move {
+            let Some(request) = next else {
+                return Ok::<_, ProviderError>(None);
+            };
+
+            let response = client
+                .send(client.get(&request.url).query(&request.query))
+                .await?;
+
+            let (items, next) = if pagination == Pagination::NextField {
+                let page = response.json::<ValuesPage<T>>().await.map_err(|e| {
+                    ProviderError::Decode(format!("Failed to parse response: {}", e))
+                })?;
+                // L'URL suivante contient déjà tous les paramètres de la requête
+                let next = page.next.map(|url| PageRequest {
+                    url,
+                    query: Vec::new(),
+                });
+                (page.values, next)
+            } else {
+                let next = next_request(&request, response.headers(), pagination);
+                let items = response.json::<Vec<T>>().await.map_err(|e| {
+                    ProviderError::Decode(format!("Failed to parse response: {}", e))
+                })?;
+                (items, next)
+            };
+
+            // Une page vide termine le parcours même si le serveur annonce une suite
+            let next = if items.is_empty() { None } else { next };
+
+            Ok(Some((items, next)))
+        }
+    })
+    .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+    .try_flatten()
+}
+
+fn next_request(
+    current: &PageRequest,
+    headers: &HeaderMap,
+    pagination: Pagination,
+) -> Option<PageRequest> {
+    match pagination {
+        Pagination::LinkHeader => {
+            let link = headers.get("link")?.to_str().ok()?;
+            // L'URL suivante contient déjà tous les paramètres de la requête
+            next_link(link).map(|url| PageRequest {
+                url,
+                query: Vec::new(),
+            })
+        }
+        Pagination::PageHeaders => {
+            let page = next_page(headers)?;
+            let mut query: Vec<_> = current
+                .query
+                .iter()
+                .filter(|(key, _)| *key != "page")
+                .cloned()
+                .collect();
+            query.push(("page", page.to_string()));
+
+            Some(PageRequest {
+                url: current.url.clone(),
+                query,
+            })
+        }
+        // La page suivante est indiquée dans le corps de la réponse
+        Pagination::NextField => None,
+    }
+}
+
+/// Extrait l'URL `rel="next"` d'un en-tête `Link`
+pub(crate) fn next_link(link: &str) -> Option<String> {
+    link.split(',').find_map(|part| {
+        let mut segments = part.split(';');
+        let url = segments.next()?.trim();
+        let is_next = segments.any(|param| {
+            let param = param.trim();
+            param == "rel=\"next\"" || param == "rel=next"
+        });
+
+        if is_next {
+            url.strip_prefix('<')?
+                .strip_suffix('>')
+                .map(|url| url.to_string())
+        } else {
+            None
+        }
+    })
+}
+
+/// Détermine la page suivante à partir des en-têtes GitLab.
+///
+/// `X-Next-Page` est vide sur la dernière page. `X-Total-Pages` n'est pas
+/// renvoyé au-delà de 10 000 éléments, il ne sert donc que de repli.
+pub(crate) fn next_page(headers: &HeaderMap) -> Option<u32> {
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<u32>().ok())
+    };
+
+    if let Some(next) = header("x-next-page") {
+        return Some(next);
+    }
+
+    match (header("x-page"), header("x-total-pages")) {
+        (Some(page), Some(total)) if page < total => Some(page + 1),
+        _ => None,
+    }
+}
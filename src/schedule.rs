@@ -0,0 +1,354 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+
+use crate::error::ProviderError;
+use crate::models::common::{Estimate, Project, HOURS_PER_DAY};
+use crate::validation::{validate_project, DependencyGraph};
+
+/// Paramètres de `report schedule`
+#[derive(Debug, Clone)]
+pub struct ScheduleOptions {
+    /// Premier jour de travail, au format YYYY-MM-DD
+    pub start: String,
+    /// Durée d'un story point, en heures
+    pub hours_per_point: f64,
+}
+
+impl Default for ScheduleOptions {
+    fn default() -> Self {
+        Self {
+            start: today(),
+            hours_per_point: HOURS_PER_DAY,
+        }
+    }
+}
+
+/// Planification d'une issue, en heures depuis le début du projet
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct IssueSchedule {
+    pub title: String,
+    /// Version du milestone
+    pub milestone: String,
+    pub sprint: u32,
+    pub hours: f64,
+    /// Début au plus tôt, une fois les dépendances terminées
+    pub earliest_start: f64,
+    pub earliest_finish: f64,
+    pub critical: bool,
+}
+
+/// Charge d'un sprint, tous milestones confondus
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SprintLoad {
+    pub sprint: u32,
+    pub issues: usize,
+    pub hours: f64,
+}
+
+/// Charge et faisabilité d'un milestone
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MilestoneSchedule {
+    pub name: String,
+    pub version: String,
+    pub deadline: String,
+    pub issues: usize,
+    pub hours: f64,
+    /// Fin au plus tôt de ses issues, en heures depuis le début du projet
+    pub earliest_finish: f64,
+    /// Dernier jour de travail au plus tôt, au format YYYY-MM-DD
+    pub projected: String,
+    /// `None` si la deadline n'est pas une date YYYY-MM-DD
+    pub feasible: Option<bool>,
+}
+
+/// Rapport de `report schedule`.
+///
+/// Les issues d'une chaîne de dépendances s'enchaînent, les autres avancent
+/// en parallèle, à raison de [`HOURS_PER_DAY`] heures par jour ouvré. Les
+/// dates projetées sont donc des bornes basses : la taille de l'équipe n'est
+/// pas prise en compte.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScheduleReport {
+    pub start: String,
+    pub issues: Vec<IssueSchedule>,
+    pub sprints: Vec<SprintLoad>,
+    pub milestones: Vec<MilestoneSchedule>,
+    /// Titres des issues du chemin critique, de la première à la dernière
+    pub critical_path: Vec<String>,
+    pub critical_path_hours: f64,
+    /// Issues dont l'estimation n'est pas reconnue, comptées pour 0 heure
+    pub unestimated: Vec<String>,
+}
+
+impl ScheduleReport {
+    /// Calcule la charge, le chemin critique et la faisabilité des deadlines de `project`
+    pub fn for_project(
+        project: &Project,
+        options: &ScheduleOptions,
+    ) -> Result<Self, ProviderError> {
+        validate_project(project).map_err(ProviderError::InvalidProject)?;
+        let start = parse_date(&options.start).ok_or_else(|| {
+            ProviderError::Config(format!("Invalid start date: {}", options.start))
+        })?;
+
+        let mut unestimated = Vec::new();
+        let hours: Vec<f64> = project
+            .issues
+            .iter()
+            .map(|issue| match issue.parsed_estimate() {
+                Some(Estimate::Hours(hours)) => hours,
+                Some(Estimate::Points(points)) => points * options.hours_per_point,
+                None => {
+                    unestimated.push(issue.title.clone());
+                    0.0
+                }
+            })
+            .collect();
+
+        // Fin au plus tôt de chaque issue, en suivant l'ordre des dépendances
+        let graph = DependencyGraph::new(project);
+        let mut earliest_start = vec![0.0; hours.len()];
+        let mut earliest_finish = vec![0.0; hours.len()];
+        let mut predecessor = vec![None; hours.len()];
+        for index in graph.topological_order() {
+            for &dependency in graph.dependencies(index) {
+                if predecessor[index].is_none()
+                    || earliest_finish[dependency] > earliest_start[index]
+                {
+                    earliest_start[index] = earliest_finish[dependency];
+                    predecessor[index] = Some(dependency);
+                }
+            }
+            earliest_finish[index] = earliest_start[index] + hours[index];
+        }
+
+        // Le chemin critique remonte depuis l'issue qui termine le plus tard
+        let mut critical = vec![false; hours.len()];
+        let mut critical_path = Vec::new();
+        let last = (0..hours.len()).fold(None, |last: Option<usize>, index| match last {
+            Some(last) if earliest_finish[last] >= earliest_finish[index] => Some(last),
+            _ => Some(index),
+        });
+        let mut current = last;
+        while let Some(index) = current {
+            critical[index] = true;
+            critical_path.push(project.issues[index].title.clone());
+            current = predecessor[index];
+        }
+        critical_path.reverse();
+
+        let issues = project
+            .issues
+            .iter()
+            .enumerate()
+            .map(|(index, issue)| IssueSchedule {
+                title: issue.title.clone(),
+                milestone: issue.milestone.clone(),
+                sprint: issue.sprint,
+                hours: hours[index],
+                earliest_start: earliest_start[index],
+                earliest_finish: earliest_finish[index],
+                critical: critical[index],
+            })
+            .collect::<Vec<_>>();
+
+        let mut sprints: BTreeMap<u32, SprintLoad> = BTreeMap::new();
+        for issue in &issues {
+            let load = sprints.entry(issue.sprint).or_insert(SprintLoad {
+                sprint: issue.sprint,
+                issues: 0,
+                hours: 0.0,
+            });
+            load.issues += 1;
+            load.hours += issue.hours;
+        }
+
+        let milestones = project
+            .milestones
+            .iter()
+            .map(|milestone| {
+                let own: Vec<&IssueSchedule> = issues
+                    .iter()
+                    .filter(|issue| issue.milestone == milestone.version)
+                    .collect();
+                let earliest_finish = own
+                    .iter()
+                    .map(|issue| issue.earliest_finish)
+                    .fold(0.0, f64::max);
+                let projected = add_working_days(start, working_days(earliest_finish));
+
+                MilestoneSchedule {
+                    name: milestone.name.clone(),
+                    version: milestone.version.clone(),
+                    deadline: milestone.deadline.clone(),
+                    issues: own.len(),
+                    hours: own.iter().map(|issue| issue.hours).sum(),
+                    earliest_finish,
+                    projected: format_date(projected),
+                    feasible: parse_date(&milestone.deadline).map(|deadline| projected <= deadline),
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            start: format_date(start),
+            issues,
+            sprints: sprints.into_values().collect(),
+            milestones,
+            critical_path,
+            critical_path_hours: last.map_or(0.0, |index| earliest_finish[index]),
+            unestimated,
+        })
+    }
+}
+
+impl fmt::Display for ScheduleReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Schedule from {}", self.start)?;
+        writeln!(f)?;
+
+        writeln!(f, "{:<8} {:>6} {:>8}", "Sprint", "Issues", "Load")?;
+        for sprint in &self.sprints {
+            writeln!(
+                f,
+                "{:<8} {:>6} {:>8}",
+                sprint.sprint,
+                sprint.issues,
+                hours(sprint.hours)
+            )?;
+        }
+        writeln!(f)?;
+
+        writeln!(
+            f,
+            "{:<20} {:>6} {:>8} {:<10}  {:<10}  Status",
+            "Milestone", "Issues", "Load", "Deadline", "Projected"
+        )?;
+        for milestone in &self.milestones {
+            let status = match milestone.feasible {
+                Some(true) => "on track",
+                Some(false) => "LATE",
+                None => "unknown deadline",
+            };
+            writeln!(
+                f,
+                "{:<20} {:>6} {:>8} {:<10}  {:<10}  {}",
+                format!("{} ({})", milestone.name, milestone.version),
+                milestone.issues,
+                hours(milestone.hours),
+                milestone.deadline,
+                milestone.projected,
+                status
+            )?;
+        }
+        writeln!(f)?;
+
+        write!(
+            f,
+            "Critical path ({}): {}",
+            hours(self.critical_path_hours),
+            self.critical_path.join(" -> ")
+        )?;
+
+        if !self.unestimated.is_empty() {
+            write!(
+                f,
+                "\nUnrecognized estimates (counted as 0h): {}",
+                self.unestimated.join(", ")
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+fn hours(hours: f64) -> String {
+    format!("{}h", (hours * 10.0).round() / 10.0)
+}
+
+/// Plafond des projections, environ 10 000 ans de jours ouvrés : au-delà, une
+/// estimation aberrante donnerait une date impossible à représenter
+const MAX_WORKING_DAYS: i64 = 2_600_000;
+
+/// Nombre de jours ouvrés nécessaires pour `hours` heures de travail
+fn working_days(hours: f64) -> i64 {
+    (hours / HOURS_PER_DAY).ceil().min(MAX_WORKING_DAYS as f64) as i64
+}
+
+/// Jour de la semaine, de 0 (lundi) à 6 (dimanche)
+fn weekday(day: i64) -> i64 {
+    // Le 1er janvier 1970 était un jeudi
+    (day + 3).rem_euclid(7)
+}
+
+/// Dernier jour ouvré d'un travail de `days` jours commencé le jour `start`
+fn add_working_days(start: i64, days: i64) -> i64 {
+    // Un travail commencé le week-end démarre le lundi suivant
+    let first = match weekday(start) {
+        5 => start + 2,
+        6 => start + 1,
+        _ => start,
+    };
+
+    // Semaines complètes depuis le lundi, puis jours restants
+    let offset = weekday(first) + (days - 1).max(0);
+    first - weekday(first) + offset / 5 * 7 + offset % 5
+}
+
+/// Date du jour au format YYYY-MM-DD
+pub fn today() -> String {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default();
+    format_date((seconds / 86_400) as i64)
+}
+
+/// Nombre de jours depuis le 1er janvier 1970 d'une date YYYY-MM-DD
+fn parse_date(date: &str) -> Option<i64> {
+    let mut parts = date.trim().splitn(3, '-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: i64 = parts.next()?.parse().ok()?;
+    let day: i64 = parts.next()?.parse().ok()?;
+
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        _ => return None,
+    };
+    if !(1..=days_in_month).contains(&day) {
+        return None;
+    }
+
+    // Algorithme « days from civil » de Howard Hinnant
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    Some(era * 146_097 + day_of_era - 719_468)
+}
+
+fn format_date(days: i64) -> String {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
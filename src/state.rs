@@ -0,0 +1,203 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::ProviderError;
+use crate::models::common::LinkKind;
+
+/// Emplacement par défaut du fichier d'état, relatif au répertoire courant
+pub const DEFAULT_STATE_PATH: &str = ".repo_manager/state.json";
+
+/// Lien créé entre deux issues, identifiées par leur titre dans le fichier projet
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct LinkState {
+    pub from: String,
+    pub to: String,
+    /// Absent des états antérieurs aux liens typés, qui ne contenaient que des dépendances
+    #[serde(default)]
+    pub kind: LinkKind,
+}
+
+/// Avancement du dernier `setup` lancé sur le repository
+#[derive(Debug, Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SetupStatus {
+    #[default]
+    NotStarted,
+    InProgress,
+    Completed,
+}
+
+#[derive(Debug, Clone)]
+struct CheckpointTarget {
+    path: PathBuf,
+    key: String,
+}
+
+/// Correspondance entre les entités d'un fichier projet et leurs identifiants distants
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct ProjectState {
+    #[serde(default)]
+    pub status: SetupStatus,
+    /// Version du milestone -> id (GitLab) ou numéro (GitHub)
+    #[serde(default)]
+    pub milestones: BTreeMap<String, i64>,
+    /// Titre de l'issue -> iid (GitLab) ou numéro (GitHub)
+    #[serde(default)]
+    pub issues: BTreeMap<String, i64>,
+    #[serde(default)]
+    pub links: Vec<LinkState>,
+    #[serde(skip)]
+    resuming: bool,
+    #[serde(skip)]
+    target: Option<CheckpointTarget>,
+}
+
+impl ProjectState {
+    pub fn milestone_id(&self, version: &str) -> Option<i64> {
+        self.milestones.get(version).copied()
+    }
+
+    pub fn issue_id(&self, title: &str) -> Option<i64> {
+        self.issues.get(title).copied()
+    }
+
+    pub fn has_link(&self, from: &str, to: &str, kind: LinkKind) -> bool {
+        self.links
+            .iter()
+            .any(|link| link.from == from && link.to == to && link.kind == kind)
+    }
+
+    /// Vrai si le précédent `setup` s'est interrompu avant la fin
+    pub fn is_interrupted(&self) -> bool {
+        self.status == SetupStatus::InProgress
+    }
+
+    /// Vrai si `setup` reprend un checkpoint : les entités enregistrées sont
+    /// considérées comme créées sans vérification auprès du provider
+    pub fn is_resuming(&self) -> bool {
+        self.resuming
+    }
+
+    pub fn record_milestone(&mut self, version: &str, id: i64) -> Result<(), ProviderError> {
+        self.milestones.insert(version.to_string(), id);
+        self.checkpoint()
+    }
+
+    pub fn record_issue(&mut self, title: &str, id: i64) -> Result<(), ProviderError> {
+        self.issues.insert(title.to_string(), id);
+        self.checkpoint()
+    }
+
+    pub fn record_link(
+        &mut self,
+        from: &str,
+        to: &str,
+        kind: LinkKind,
+    ) -> Result<(), ProviderError> {
+        if !self.has_link(from, to, kind) {
+            self.links.push(LinkState {
+                from: from.to_string(),
+                to: to.to_string(),
+                kind,
+            });
+        }
+        self.checkpoint()
+    }
+
+    /// Démarre un `setup`. Avec `resume`, reprend un `setup` interrompu.
+    pub fn begin(&mut self, resume: bool) -> Result<(), ProviderError> {
+        if resume && !self.is_interrupted() {
+            return Err(ProviderError::Config(
+                "No interrupted setup to resume".to_string(),
+            ));
+        }
+
+        self.resuming = resume;
+        self.status = SetupStatus::InProgress;
+        self.checkpoint()
+    }
+
+    pub fn complete(&mut self) -> Result<(), ProviderError> {
+        self.resuming = false;
+        self.status = SetupStatus::Completed;
+        self.checkpoint()
+    }
+
+    /// Sauvegarde l'état dans le fichier d'où il a été chargé.
+    ///
+    /// Sans fichier associé (état créé en mémoire), ne fait rien.
+    pub fn checkpoint(&self) -> Result<(), ProviderError> {
+        let Some(target) = &self.target else {
+            return Ok(());
+        };
+
+        // Relire le fichier pour ne pas écraser les autres repositories
+        let mut state_file = StateFile::load(&target.path)?;
+        state_file
+            .repositories
+            .insert(target.key.clone(), self.clone());
+        state_file.save(&target.path)
+    }
+}
+
+/// Fichier d'état regroupant un [`ProjectState`] par provider et repository
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct StateFile {
+    #[serde(default)]
+    pub repositories: BTreeMap<String, ProjectState>,
+}
+
+impl StateFile {
+    /// Clé identifiant un repository dans le fichier, par exemple `github:owner/repo`
+    pub fn key(provider: &str, repository: &str) -> String {
+        format!("{}:{}", provider, repository)
+    }
+
+    /// Charge le fichier d'état, ou retourne un état vide s'il n'existe pas encore
+    pub fn load(path: &Path) -> Result<Self, ProviderError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(path)
+            .map_err(|e| ProviderError::Config(format!("Failed to read state file: {}", e)))?;
+
+        serde_json::from_str(&content)
+            .map_err(|e| ProviderError::Config(format!("Failed to parse state file: {}", e)))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), ProviderError> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        // Écriture atomique : un fichier tronqué ferait perdre toutes les correspondances
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_string_pretty(self)?)?;
+        fs::rename(tmp, path)?;
+
+        Ok(())
+    }
+
+    /// Charge l'état d'un repository et l'associe au fichier : chaque
+    /// enregistrement est alors sauvegardé immédiatement (checkpoint).
+    pub fn open_project(path: &Path, key: &str) -> Result<ProjectState, ProviderError> {
+        let mut state = Self::load(path)?.project(key).cloned().unwrap_or_default();
+        state.target = Some(CheckpointTarget {
+            path: path.to_path_buf(),
+            key: key.to_string(),
+        });
+
+        Ok(state)
+    }
+
+    pub fn project(&self, key: &str) -> Option<&ProjectState> {
+        self.repositories.get(key)
+    }
+
+    pub fn project_mut(&mut self, key: &str) -> &mut ProjectState {
+        self.repositories.entry(key.to_string()).or_default()
+    }
+}
@@ -0,0 +1,200 @@
+use crate::error::ProviderError;
+use crate::models::common::Label;
+use crate::traits::repository::RepositoryProvider;
+
+/// Action nécessaire pour aligner un label distant sur la configuration
+#[derive(Debug, Clone)]
+pub enum LabelChange {
+    Create(Label),
+    Update { current: Label, desired: Label },
+    Delete(Label),
+    Unchanged(Label),
+}
+
+impl LabelChange {
+    pub fn name(&self) -> &str {
+        match self {
+            LabelChange::Create(label)
+            | LabelChange::Delete(label)
+            | LabelChange::Unchanged(label) => &label.name,
+            LabelChange::Update { desired, .. } => &desired.name,
+        }
+    }
+}
+
+/// Résultat d'une synchronisation de labels
+#[derive(Debug, Default)]
+pub struct LabelSyncReport {
+    pub created: Vec<String>,
+    pub updated: Vec<String>,
+    pub deleted: Vec<String>,
+    pub unchanged: Vec<String>,
+    pub failed: Vec<(String, ProviderError)>,
+}
+
+impl LabelSyncReport {
+    pub fn summary(&self) -> String {
+        format!(
+            "{} created, {} updated, {} deleted, {} unchanged, {} failed",
+            self.created.len(),
+            self.updated.len(),
+            self.deleted.len(),
+            self.unchanged.len(),
+            self.failed.len()
+        )
+    }
+}
+
+// Les noms de labels sont insensibles à la casse sur GitHub comme sur GitLab
+fn same_name(a: &str, b: &str) -> bool {
+    a.to_lowercase() == b.to_lowercase()
+}
+
+fn normalize_color(color: &str) -> String {
+    color.trim_start_matches('#').to_lowercase()
+}
+
+/// Champs des labels conservés par le provider, seuls comparés par [`diff_labels`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LabelCapabilities {
+    /// Couleur et description
+    pub colors: bool,
+    /// Suppression, sans laquelle `prune` est ignoré
+    pub delete: bool,
+}
+
+impl Default for LabelCapabilities {
+    fn default() -> Self {
+        Self {
+            colors: true,
+            delete: true,
+        }
+    }
+}
+
+impl LabelCapabilities {
+    pub fn of(provider: &dyn RepositoryProvider) -> Self {
+        Self {
+            colors: provider.supports_label_colors(),
+            delete: provider.supports_label_deletion(),
+        }
+    }
+}
+
+fn has_drifted(current: &Label, desired: &Label, capabilities: LabelCapabilities) -> bool {
+    capabilities.colors
+        && (normalize_color(&current.color) != normalize_color(&desired.color)
+            || current.description.as_deref().unwrap_or_default()
+                != desired.description.as_deref().unwrap_or_default())
+}
+
+/// Compare les labels souhaités aux labels existants.
+///
+/// Les labels absents de la configuration ne sont supprimés que si `prune` est activé
+/// et que le provider sait les supprimer. Un provider sans couleurs ne produit jamais
+/// de mise à jour.
+pub fn diff_labels(
+    desired: &[Label],
+    existing: &[Label],
+    prune: bool,
+    capabilities: LabelCapabilities,
+) -> Vec<LabelChange> {
+    let mut changes = Vec::new();
+
+    for label in desired {
+        match existing.iter().find(|e| same_name(&e.name, &label.name)) {
+            None => changes.push(LabelChange::Create(label.clone())),
+            Some(current) if has_drifted(current, label, capabilities) => {
+                changes.push(LabelChange::Update {
+                    current: current.clone(),
+                    desired: Label {
+                        // On conserve le nom distant pour cibler le bon label
+                        name: current.name.clone(),
+                        ..label.clone()
+                    },
+                })
+            }
+            Some(current) => changes.push(LabelChange::Unchanged(current.clone())),
+        }
+    }
+
+    if prune && capabilities.delete {
+        for label in existing {
+            if !desired.iter().any(|d| same_name(&d.name, &label.name)) {
+                changes.push(LabelChange::Delete(label.clone()));
+            }
+        }
+    }
+
+    changes
+}
+
+/// Applique les changements calculés par [`diff_labels`].
+///
+/// Un échec sur un label n'interrompt pas la synchronisation des autres.
+pub async fn apply_label_changes(
+    provider: &dyn RepositoryProvider,
+    changes: Vec<LabelChange>,
+) -> LabelSyncReport {
+    let mut report = LabelSyncReport::default();
+
+    for change in changes {
+        let name = change.name().to_string();
+        let result = match &change {
+            LabelChange::Create(label) => provider.create_label(label).await.map(|_| ()),
+            LabelChange::Update { desired, .. } => provider.update_label(desired).await,
+            LabelChange::Delete(label) => provider.delete_label(&label.name).await,
+            LabelChange::Unchanged(_) => Ok(()),
+        };
+
+        match (result, change) {
+            (Err(e), _) => report.failed.push((name, e)),
+            (Ok(()), LabelChange::Create(_)) => report.created.push(name),
+            (Ok(()), LabelChange::Update { .. }) => report.updated.push(name),
+            (Ok(()), LabelChange::Delete(_)) => report.deleted.push(name),
+            (Ok(()), LabelChange::Unchanged(_)) => report.unchanged.push(name),
+        }
+    }
+
+    report
+}
+
+/// Renomme les labels comme le provider les enregistre (voir [`RepositoryProvider::label_name`])
+pub fn normalize_labels(provider: &dyn RepositoryProvider, labels: &[Label]) -> Vec<Label> {
+    labels
+        .iter()
+        .map(|label| Label {
+            name: provider.label_name(&label.name),
+            ..label.clone()
+        })
+        .collect()
+}
+
+/// Calcule les changements nécessaires pour aligner les labels du repository sur
+/// `desired`, dont les noms sont d'abord normalisés comme le provider les enregistre
+pub async fn plan_label_changes(
+    provider: &dyn RepositoryProvider,
+    desired: &[Label],
+    prune: bool,
+) -> Result<Vec<LabelChange>, ProviderError> {
+    let existing = provider.list_labels().await?;
+    let desired = normalize_labels(provider, desired);
+
+    Ok(diff_labels(
+        &desired,
+        &existing,
+        prune,
+        LabelCapabilities::of(provider),
+    ))
+}
+
+/// Aligne les labels du repository sur `desired`
+pub async fn sync_labels(
+    provider: &dyn RepositoryProvider,
+    desired: &[Label],
+    prune: bool,
+) -> Result<LabelSyncReport, ProviderError> {
+    let changes = plan_label_changes(provider, desired, prune).await?;
+
+    Ok(apply_label_changes(provider, changes).await)
+}
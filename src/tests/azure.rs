@@ -0,0 +1,295 @@
+use serde_json::{json, Value};
+use wiremock::matchers::{body_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+use super::{label, mock_config, sample_project};
+use crate::engine::setup_project;
+use crate::error::ProviderError;
+use crate::models::common::{IssueCreate, IssueFilter, IssueState, LinkKind};
+use crate::providers::AzureDevOpsProvider;
+use crate::state::ProjectState;
+use crate::sync::sync_labels;
+use crate::traits::repository::RepositoryProvider;
+
+const PROJECT: &str = "/org/My%20Project/_apis";
+
+fn provider(server: &MockServer) -> AzureDevOpsProvider {
+    AzureDevOpsProvider::new(mock_config(server, "org/My Project")).unwrap()
+}
+
+/// Vérifie qu'une opération JSON Patch `add` porte sur `path` avec `value`
+fn patch_op(path: &'static str, value: Value) -> impl Fn(&Request) -> bool + Send + Sync {
+    move |request: &Request| {
+        serde_json::from_slice::<Vec<Value>>(&request.body)
+            .map(|operations| {
+                operations.iter().any(|operation| {
+                    operation["op"] == "add"
+                        && operation["path"] == path
+                        && operation["value"] == value
+                })
+            })
+            .unwrap_or(false)
+    }
+}
+
+fn work_item(id: i64, title: &str, state: &str, iteration_path: &str) -> Value {
+    json!({
+        "id": id,
+        "fields": {
+            "System.Title": title,
+            "System.Description": "Body",
+            "System.State": state,
+            "System.Tags": "backend; type::bug",
+            "System.IterationPath": iteration_path
+        }
+    })
+}
+
+#[tokio::test]
+async fn test_azure_setup_project_creates_iterations_and_work_items() {
+    let server = MockServer::start().await;
+
+    // Projet vide : aucune itération ni work item
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "{}/wit/classificationnodes/Iterations",
+            PROJECT
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 1,
+            "name": "My Project"
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path(format!("{}/wit/wiql", PROJECT)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"workItems": []})))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "{}/wit/classificationnodes/Iterations",
+            PROJECT
+        )))
+        .and(header("authorization", "Basic OnRlc3RfdG9rZW4="))
+        .and(query_param("api-version", "7.1"))
+        .and(body_json(json!({
+            "name": "MVP",
+            "attributes": {"finishDate": "2024-12-31T00:00:00Z"}
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "id": 10,
+            "name": "MVP"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "{}/wit/classificationnodes/Iterations/MVP/Sprint%201",
+            PROJECT
+        )))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "message": "VS402485: The node does not exist."
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "{}/wit/classificationnodes/Iterations/MVP",
+            PROJECT
+        )))
+        .and(body_json(json!({"name": "Sprint 1"})))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "id": 11,
+            "name": "Sprint 1"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    for (id, title) in [(1, "Setup CI"), (2, "Write API")] {
+        Mock::given(method("POST"))
+            .and(path(format!("{}/wit/workitems/$User%20Story", PROJECT)))
+            .and(header("content-type", "application/json-patch+json"))
+            .and(patch_op("/fields/System.Title", json!(title)))
+            .and(patch_op(
+                "/fields/System.IterationPath",
+                json!("My Project\\MVP\\Sprint 1"),
+            ))
+            .and(patch_op(
+                "/fields/Microsoft.VSTS.Scheduling.StoryPoints",
+                json!(1.0),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"id": id})))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    Mock::given(method("GET"))
+        .and(path(format!("{}/wit/workitems/2", PROJECT)))
+        .and(query_param("$expand", "relations"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(work_item(
+            2,
+            "Write API",
+            "New",
+            "My Project\\MVP\\Sprint 1",
+        )))
+        .mount(&server)
+        .await;
+
+    let predecessor = format!("{}/org/_apis/wit/workItems/1", server.uri());
+    Mock::given(method("PATCH"))
+        .and(path(format!("{}/wit/workitems/2", PROJECT)))
+        .and(patch_op(
+            "/relations/-",
+            json!({
+                "rel": "System.LinkTypes.Dependency-Reverse",
+                "url": predecessor
+            }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"id": 2})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut state = ProjectState::default();
+    setup_project(&provider(&server), &sample_project(), &mut state)
+        .await
+        .unwrap();
+
+    assert_eq!(state.milestone_id("0.1.0"), Some(10));
+    assert_eq!(state.issue_id("Write API"), Some(2));
+    assert!(state.has_link("Write API", "Setup CI", LinkKind::BlockedBy));
+}
+
+#[tokio::test]
+async fn test_azure_list_issues_uses_wiql() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path(format!("{}/wit/wiql", PROJECT)))
+        .and(|request: &Request| {
+            let body: Value = serde_json::from_slice(&request.body).unwrap();
+            let query = body["query"].as_str().unwrap_or_default();
+            query.contains("[System.State] IN ('Closed', 'Done', 'Removed', 'Resolved')")
+                && query.contains("[System.Tags] CONTAINS 'backend'")
+                && query.contains("[System.IterationPath] UNDER 'My Project\\MVP'")
+        })
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "workItems": [{"id": 4}, {"id": 5}]
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("{}/wit/workitems", PROJECT)))
+        .and(query_param("ids", "4,5"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "value": [
+                work_item(4, "Done item", "Done", "My Project\\MVP\\Sprint 2"),
+                work_item(5, "Closed item", "Closed", "My Project"),
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    let issues = provider(&server)
+        .list_issues(&IssueFilter {
+            state: Some(IssueState::Closed),
+            labels: vec!["backend".to_string()],
+            milestone: Some("MVP".to_string()),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(issues.len(), 2);
+    assert_eq!(issues[0].state, IssueState::Closed);
+    assert_eq!(issues[0].labels, ["backend", "type::bug"]);
+    assert_eq!(issues[0].milestone.as_deref(), Some("MVP"));
+    assert_eq!(issues[1].milestone, None);
+    assert!(issues[0]
+        .web_url
+        .ends_with("/org/My%20Project/_workitems/edit/4"));
+}
+
+#[tokio::test]
+async fn test_azure_label_sync_converges() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("{}/wit/tags", PROJECT)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "count": 1,
+            "value": [{"id": "8f2c", "name": "type::bug"}]
+        })))
+        .mount(&server)
+        .await;
+
+    // Les tags existants ne sont jamais « mis à jour » faute de couleur
+    let desired = [label(
+        "type::bug",
+        "#d73a4a",
+        Some("Something isn't working"),
+    )];
+    let report = sync_labels(&provider(&server), &desired, false)
+        .await
+        .unwrap();
+
+    assert!(report.updated.is_empty());
+    assert_eq!(report.unchanged, ["type::bug"]);
+}
+
+#[tokio::test]
+async fn test_azure_create_issue_sets_iteration_and_assignee() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path(format!("{}/wit/workitems/$User%20Story", PROJECT)))
+        .and(patch_op(
+            "/fields/System.IterationPath",
+            json!("My Project\\MVP"),
+        ))
+        .and(patch_op(
+            "/fields/System.AssignedTo",
+            json!("jdoe@example.com"),
+        ))
+        // Le poids devient les story points
+        .and(patch_op(
+            "/fields/Microsoft.VSTS.Scheduling.StoryPoints",
+            json!(3.0),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"id": 3})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+    let mut issue = IssueCreate {
+        title: "Fix login".to_string(),
+        milestone: Some("MVP".to_string()),
+        assignees: vec!["jdoe@example.com".to_string()],
+        weight: Some(3),
+        ..Default::default()
+    };
+    assert_eq!(provider.create_issue(&issue).await.unwrap().id, 3);
+
+    // Une seule personne assignée et pas d'échéance : rien n'est créé
+    issue.assignees.push("hubot@example.com".to_string());
+    assert!(matches!(
+        provider.create_issue(&issue).await,
+        Err(ProviderError::Config(_))
+    ));
+
+    issue.assignees.truncate(1);
+    issue.due_date = Some("2024-12-31".to_string());
+    assert!(matches!(
+        provider.create_issue(&issue).await,
+        Err(ProviderError::Config(_))
+    ));
+}
@@ -0,0 +1,276 @@
+use serde_json::json;
+use wiremock::matchers::{body_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use super::{label, mock_config};
+use crate::error::ProviderError;
+use crate::models::common::{IssueCreate, IssueFilter, IssueState, LinkKind, Milestone};
+use crate::plan::plan_labels;
+use crate::providers::BitbucketProvider;
+use crate::sync::sync_labels;
+use crate::traits::repository::RepositoryProvider;
+
+fn provider(server: &MockServer) -> BitbucketProvider {
+    BitbucketProvider::new(mock_config(server, "workspace/repo")).unwrap()
+}
+
+async fn mount_named(server: &MockServer, resource: &str, values: serde_json::Value) {
+    Mock::given(method("GET"))
+        .and(path(format!("/repositories/workspace/repo/{}", resource)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "values": values,
+            "next": null
+        })))
+        .mount(server)
+        .await;
+}
+
+fn milestone() -> Milestone {
+    Milestone {
+        name: "MVP".to_string(),
+        version: "0.1.0".to_string(),
+        deadline: "2024-12-31".to_string(),
+        description: "First release".to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_bitbucket_labels_are_kinds_and_components() {
+    let server = MockServer::start().await;
+    mount_named(&server, "components", json!([{"id": 1, "name": "backend"}])).await;
+
+    let labels = provider(&server).list_labels().await.unwrap();
+    let names: Vec<&str> = labels.iter().map(|label| label.name.as_str()).collect();
+
+    assert_eq!(names, ["bug", "enhancement", "proposal", "task", "backend"]);
+}
+
+#[tokio::test]
+async fn test_bitbucket_label_sync_converges() {
+    let server = MockServer::start().await;
+    mount_named(&server, "components", json!([{"id": 1, "name": "backend"}])).await;
+
+    // Les couleurs de la configuration n'existent pas côté Bitbucket
+    let desired = [
+        label("bug", "#d73a4a", Some("Something isn't working")),
+        label("backend", "#428bca", None),
+    ];
+    let report = sync_labels(&provider(&server), &desired, false)
+        .await
+        .unwrap();
+
+    assert!(report.updated.is_empty());
+    assert_eq!(report.unchanged, ["bug", "backend"]);
+}
+
+#[tokio::test]
+async fn test_bitbucket_labels_plan_uses_provider_names() {
+    let server = MockServer::start().await;
+    mount_named(&server, "components", json!([{"id": 1, "name": "backend"}])).await;
+
+    let desired = [
+        label("type::bug", "#d73a4a", None),
+        label("backend", "#428bca", None),
+    ];
+    let plan = plan_labels(&provider(&server), &desired).await.unwrap();
+
+    assert!(!plan.has_changes());
+    assert!(plan
+        .to_string()
+        .contains("= label \"bug\" (already exists)"));
+}
+
+#[tokio::test]
+async fn test_bitbucket_create_issue_maps_kind_and_component() {
+    let server = MockServer::start().await;
+    mount_named(&server, "components", json!([{"id": 1, "name": "backend"}])).await;
+
+    Mock::given(method("POST"))
+        .and(path("/repositories/workspace/repo/issues"))
+        .and(header("authorization", "Bearer test_token"))
+        .and(body_json(json!({
+            "title": "Fix login",
+            "content": {"raw": "Steps to reproduce", "markup": "markdown"},
+            "kind": "bug",
+            "component": {"name": "backend"}
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({"id": 7})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    provider(&server)
+        .create_issue(&IssueCreate {
+            title: "Fix login".to_string(),
+            description: "Steps to reproduce".to_string(),
+            labels: vec![
+                "priority::high".to_string(),
+                "type::bug".to_string(),
+                "Backend".to_string(),
+            ],
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_bitbucket_create_issue_resolves_milestone() {
+    let server = MockServer::start().await;
+    mount_named(&server, "milestones", json!([])).await;
+    mount_named(&server, "versions", json!([{"id": 12, "name": "0.1.0"}])).await;
+
+    Mock::given(method("POST"))
+        .and(path("/repositories/workspace/repo/issues"))
+        .and(body_json(json!({
+            "title": "Fix login",
+            "content": {"raw": "", "markup": "markdown"},
+            "version": {"name": "0.1.0"}
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({"id": 7})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+    let mut issue = IssueCreate {
+        title: "Fix login".to_string(),
+        milestone: Some("0.1.0".to_string()),
+        ..Default::default()
+    };
+    provider.create_issue(&issue).await.unwrap();
+
+    // Ni personnes assignées par nom, ni échéance, ni poids : rien n'est créé
+    issue.assignees = vec!["jdoe".to_string()];
+    let error = provider.create_issue(&issue).await.unwrap_err();
+    assert!(matches!(error, ProviderError::Config(message) if message.contains("jdoe")));
+
+    issue.assignees.clear();
+    issue.due_date = Some("2024-12-31".to_string());
+    assert!(matches!(
+        provider.create_issue(&issue).await,
+        Err(ProviderError::Config(_))
+    ));
+
+    issue.due_date = None;
+    issue.weight = Some(3);
+    let error = provider.create_issue(&issue).await.unwrap_err();
+    assert!(matches!(error, ProviderError::Config(message) if message.contains("weight")));
+}
+
+#[tokio::test]
+async fn test_bitbucket_milestone_falls_back_to_version() {
+    let server = MockServer::start().await;
+    mount_named(&server, "milestones", json!([])).await;
+    mount_named(&server, "versions", json!([{"id": 12, "name": "0.1.0"}])).await;
+
+    let provider = provider(&server);
+    assert_eq!(
+        provider.create_milestone(&milestone()).await.unwrap().id,
+        12
+    );
+
+    let unknown = Milestone {
+        version: "2.0.0".to_string(),
+        ..milestone()
+    };
+    assert!(matches!(
+        provider.create_milestone(&unknown).await,
+        Err(ProviderError::Config(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_bitbucket_list_issues_follows_next_field() {
+    let server = MockServer::start().await;
+    let next = format!("{}/repositories/workspace/repo/issues?page=2", server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/repositories/workspace/repo/issues"))
+        .and(query_param(
+            "q",
+            r#"(state="new" OR state="open" OR state="on hold")"#,
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "values": [{
+                "id": 1,
+                "title": "First",
+                "content": {"raw": "Body"},
+                "state": "new",
+                "kind": "task",
+                "component": {"name": "backend"},
+                "milestone": null,
+                "version": {"name": "0.1.0"},
+                "links": {"html": {"href": "https://bitbucket.org/workspace/repo/issues/1"}}
+            }],
+            "next": next
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/repositories/workspace/repo/issues"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "values": [{
+                "id": 2,
+                "title": "Second",
+                "content": {"raw": null},
+                "state": "on hold",
+                "kind": "bug",
+                "component": null,
+                "milestone": {"name": "MVP"},
+                "version": null,
+                "links": {"html": {"href": "https://bitbucket.org/workspace/repo/issues/2"}}
+            }]
+        })))
+        .mount(&server)
+        .await;
+
+    let issues = provider(&server)
+        .list_issues(&IssueFilter {
+            state: Some(IssueState::Open),
+            ..IssueFilter::default()
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(issues.len(), 2);
+    assert_eq!(issues[0].labels, ["task", "backend"]);
+    assert_eq!(issues[0].milestone.as_deref(), Some("0.1.0"));
+    assert_eq!(issues[1].state, IssueState::Open);
+    assert_eq!(issues[1].milestone.as_deref(), Some("MVP"));
+}
+
+#[tokio::test]
+async fn test_bitbucket_dependency_comment_is_not_duplicated() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repositories/workspace/repo/issues/3/comments"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "values": [{"content": {"raw": "Depends on #1"}}]
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/repositories/workspace/repo/issues/3/comments"))
+        .and(body_json(json!({
+            "content": {"raw": "Depends on #2", "markup": "markdown"}
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({"id": 100})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+    provider
+        .create_issue_link(3, 2, LinkKind::BlockedBy)
+        .await
+        .unwrap();
+    provider
+        .create_issue_link(3, 1, LinkKind::BlockedBy)
+        .await
+        .unwrap();
+}
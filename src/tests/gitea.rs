@@ -0,0 +1,396 @@
+use serde_json::json;
+use wiremock::matchers::{body_json, header, method, path, query_param, query_param_is_missing};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use super::mock_config;
+use crate::error::ProviderError;
+use crate::models::common::{IssueCreate, IssueFilter, Label, LinkKind, Milestone};
+use crate::providers::{GiteaProvider, ProviderConfig};
+use crate::traits::repository::RepositoryProvider;
+
+fn provider(server: &MockServer) -> GiteaProvider {
+    GiteaProvider::new(mock_config(server, "owner/repo")).unwrap()
+}
+
+async fn mount_labels(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/labels"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {"id": 10, "name": "type::bug", "color": "d73a4a", "description": ""},
+            {"id": 11, "name": "priority::1", "color": "#ff0000", "description": "Urgent"}
+        ])))
+        .mount(server)
+        .await;
+}
+
+#[test]
+fn test_gitea_requires_owner_and_repo() {
+    let config = ProviderConfig::builder()
+        .api_url("http://localhost:3000/api/v1")
+        .token("gitea_token")
+        .repository("repo")
+        .build()
+        .unwrap();
+
+    assert!(GiteaProvider::new(config).is_err());
+}
+
+#[tokio::test]
+async fn test_gitea_create_label_and_milestone() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/labels"))
+        .and(header("authorization", "token test_token"))
+        .and(body_json(json!({
+            "name": "type::bug",
+            "color": "#d73a4a",
+            "description": ""
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "id": 10,
+            "name": "type::bug",
+            "color": "d73a4a",
+            "description": ""
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/milestones"))
+        .and(body_json(json!({
+            "title": "MVP",
+            "description": "First release",
+            "due_on": "2024-12-31T00:00:00Z"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "id": 4,
+            "title": "MVP",
+            "description": "First release",
+            "due_on": "2024-12-31T00:00:00Z",
+            "state": "open"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+
+    let label = provider
+        .create_label(&Label {
+            name: "type::bug".to_string(),
+            color: "#d73a4a".to_string(),
+            description: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(label.map(|label| label.id), Some(10));
+
+    let remote = provider
+        .create_milestone(&Milestone {
+            name: "MVP".to_string(),
+            version: "0.1.0".to_string(),
+            deadline: "2024-12-31".to_string(),
+            description: "First release".to_string(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(remote.id, 4);
+}
+
+#[tokio::test]
+async fn test_gitea_create_issue_resolves_label_ids() {
+    let server = MockServer::start().await;
+    mount_labels(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/issues"))
+        .and(body_json(json!({
+            "title": "Fix login",
+            "body": "Steps to reproduce",
+            "labels": [11, 10],
+            "milestone": null
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "id": 170,
+            "number": 42,
+            "html_url": "https://gitea.example.com/owner/repo/issues/42"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let remote = provider(&server)
+        .create_issue(&IssueCreate {
+            title: "Fix login".to_string(),
+            description: "Steps to reproduce".to_string(),
+            labels: vec!["priority::1".to_string(), "Type::Bug".to_string()],
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(remote.id, 42);
+    assert_eq!(remote.global_id, Some(170));
+}
+
+#[tokio::test]
+async fn test_gitea_update_and_delete_label_by_id() {
+    let server = MockServer::start().await;
+    mount_labels(&server).await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/repos/owner/repo/labels/11"))
+        .and(body_json(json!({
+            "name": "priority::1",
+            "color": "#cc0000",
+            "description": "Drop everything"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"id": 11})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/repos/owner/repo/labels/10"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+    provider
+        .update_label(&Label {
+            name: "priority::1".to_string(),
+            color: "#cc0000".to_string(),
+            description: Some("Drop everything".to_string()),
+        })
+        .await
+        .unwrap();
+    provider.delete_label("type::bug").await.unwrap();
+
+    let labels = provider.list_labels().await.unwrap();
+    assert_eq!(labels[0].color, "#d73a4a");
+    assert_eq!(labels[0].description, None);
+}
+
+#[tokio::test]
+async fn test_gitea_list_issues_follows_link_header() {
+    let server = MockServer::start().await;
+    let next = format!(
+        "<{}/repos/owner/repo/issues?page=2&limit=50&state=all&type=issues>; rel=\"next\"",
+        server.uri()
+    );
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/issues"))
+        .and(query_param("type", "issues"))
+        .and(query_param_is_missing("page"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("link", next.as_str())
+                .set_body_json(json!([{
+                    "number": 1,
+                    "title": "First",
+                    "body": null,
+                    "state": "open",
+                    "labels": [{"name": "type::bug"}],
+                    "milestone": {"title": "MVP"},
+                    "html_url": "http://forgejo/owner/repo/issues/1"
+                }])),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/issues"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([{
+            "number": 2,
+            "title": "Second",
+            "body": "Body",
+            "state": "closed",
+            "labels": [],
+            "milestone": null,
+            "html_url": "http://forgejo/owner/repo/issues/2"
+        }])))
+        .mount(&server)
+        .await;
+
+    let issues = provider(&server)
+        .list_issues(&IssueFilter::default())
+        .await
+        .unwrap();
+
+    assert_eq!(issues.len(), 2);
+    assert_eq!(issues[0].labels, vec!["type::bug".to_string()]);
+    assert_eq!(issues[0].milestone.as_deref(), Some("MVP"));
+    assert_eq!(issues[1].description, "Body");
+}
+
+#[tokio::test]
+async fn test_gitea_create_issue_resolves_milestone_and_assignees() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/milestones"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {"id": 5, "title": "MVP", "description": null, "due_on": null, "state": "open"}
+        ])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/issues"))
+        .and(body_json(json!({
+            "title": "Fix login",
+            "body": "",
+            "labels": [],
+            "milestone": 5,
+            "assignees": ["jdoe"],
+            "due_date": "2024-12-31T00:00:00Z"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "id": 170,
+            "number": 42,
+            "html_url": "https://gitea.example.com/owner/repo/issues/42"
+        })))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    // Les milestones ne sont listés qu'une fois pour toutes les issues
+    let provider = provider(&server);
+    let mut issue = IssueCreate {
+        title: "Fix login".to_string(),
+        milestone: Some("MVP".to_string()),
+        assignees: vec!["jdoe".to_string()],
+        due_date: Some("2024-12-31".to_string()),
+        ..Default::default()
+    };
+    provider.create_issue(&issue).await.unwrap();
+    provider.create_issue(&issue).await.unwrap();
+
+    issue.milestone = Some("2.0".to_string());
+    let error = provider.create_issue(&issue).await.unwrap_err();
+    assert!(matches!(error, ProviderError::Config(message) if message.contains("2.0")));
+
+    // Gitea n'a pas de poids : l'issue est refusée plutôt que créée sans
+    issue.milestone = Some("MVP".to_string());
+    issue.weight = Some(3);
+    let error = provider.create_issue(&issue).await.unwrap_err();
+    assert!(matches!(error, ProviderError::Config(message) if message.contains("weight")));
+}
+
+#[tokio::test]
+async fn test_gitea_create_issue_rejects_unknown_label() {
+    let server = MockServer::start().await;
+    mount_labels(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/issues"))
+        .respond_with(ResponseTemplate::new(201))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let error = provider(&server)
+        .create_issue(&IssueCreate {
+            title: "Fix login".to_string(),
+            labels: vec!["type::bug".to_string(), "status::todo".to_string()],
+            ..Default::default()
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(error, ProviderError::Config(message) if message.contains("status::todo")));
+}
+
+#[tokio::test]
+async fn test_gitea_issue_dependency() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/issues/3/dependencies"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([{"number": 1}])))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/issues/3/dependencies"))
+        .and(body_json(
+            json!({"owner": "owner", "repo": "repo", "index": 2}),
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({"number": 2})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+    provider
+        .create_issue_link(3, 2, LinkKind::BlockedBy)
+        .await
+        .unwrap();
+    // Dépendance déjà présente : aucune requête POST supplémentaire
+    provider
+        .create_issue_link(3, 1, LinkKind::BlockedBy)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_gitea_link_dedup_reads_every_page() {
+    let server = MockServer::start().await;
+
+    for resource in ["dependencies", "comments"] {
+        let next = format!(
+            "<{}/repos/owner/repo/issues/3/{}?limit=50&page=2>; rel=\"next\"",
+            server.uri(),
+            resource
+        );
+        Mock::given(method("GET"))
+            .and(path(format!("/repos/owner/repo/issues/3/{}", resource)))
+            .and(query_param("limit", "50"))
+            .and(query_param_is_missing("page"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("link", next.as_str())
+                    .set_body_json(json!([{"number": 5, "body": "Looks good"}])),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    // Le lien existant n'apparaît que sur la deuxième page
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/issues/3/dependencies"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([{"number": 1}])))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/issues/3/comments"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([{"body": "Related to #2"}])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(201))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+    provider
+        .create_issue_link(3, 1, LinkKind::BlockedBy)
+        .await
+        .unwrap();
+    provider
+        .create_issue_link(3, 2, LinkKind::RelatesTo)
+        .await
+        .unwrap();
+}
@@ -0,0 +1,580 @@
+use serde_json::json;
+use wiremock::matchers::{
+    body_json, body_partial_json, header, method, path, query_param, query_param_is_missing,
+};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use super::{fixture, label, mock_config, sample_project};
+use crate::error::ProviderError;
+use crate::models::common::{IssueCreate, IssueFilter, IssueState, LinkKind, Milestone, RemoteRef};
+use crate::providers::GitHubProvider;
+use crate::traits::repository::RepositoryProvider;
+
+fn provider(server: &MockServer) -> GitHubProvider {
+    GitHubProvider::new(mock_config(server, "owner/repo")).unwrap()
+}
+
+/// Requête portant les en-têtes attendus par l'API REST de GitHub
+fn github_request(verb: &str, url_path: &str) -> wiremock::MockBuilder {
+    Mock::given(method(verb))
+        .and(path(url_path))
+        .and(header("authorization", "Bearer test_token"))
+        .and(header("x-github-api-version", "2022-11-28"))
+        .and(header("accept", "application/vnd.github+json"))
+}
+
+#[tokio::test]
+async fn test_github_label_requests() {
+    let server = MockServer::start().await;
+
+    // La couleur est envoyée sans #
+    github_request("POST", "/repos/owner/repo/labels")
+        .and(body_json(json!({
+            "name": "type::bug",
+            "color": "d73a4a",
+            "description": "Something isn't working"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(fixture("github/label.json")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    github_request("PATCH", "/repos/owner/repo/labels/type%3A%3Abug")
+        .and(body_json(json!({"color": "ff0000", "description": null})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("github/label.json")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    github_request("DELETE", "/repos/owner/repo/labels/type%3A%3Abug")
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+    let remote = provider
+        .create_label(&label(
+            "type::bug",
+            "#d73a4a",
+            Some("Something isn't working"),
+        ))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(remote.id, 208045946);
+    assert_eq!(remote.node_id.as_deref(), Some("MDU6TGFiZWwyMDgwNDU5NDY="));
+    provider
+        .update_label(&label("type::bug", "#ff0000", None))
+        .await
+        .unwrap();
+    provider.delete_label("type::bug").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_github_list_labels_follows_link_header() {
+    let server = MockServer::start().await;
+    let next = format!(
+        "<{}/repositories/1/labels?per_page=100&page=2>; rel=\"next\", <{}/repositories/1/labels?per_page=100&page=2>; rel=\"last\"",
+        server.uri(),
+        server.uri()
+    );
+
+    github_request("GET", "/repos/owner/repo/labels")
+        .and(query_param("per_page", "100"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("link", next.as_str())
+                .set_body_json(fixture("github/labels_page1.json")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    github_request("GET", "/repositories/1/labels")
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("github/labels_page2.json")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let labels = provider(&server).list_labels().await.unwrap();
+
+    assert_eq!(labels.len(), 2);
+    assert_eq!(labels[0].name, "type::bug");
+    assert_eq!(labels[0].color, "#d73a4a");
+    assert_eq!(
+        labels[0].description.as_deref(),
+        Some("Something isn't working")
+    );
+    assert_eq!(labels[1].name, "priority::1");
+    assert_eq!(labels[1].description, None);
+}
+
+#[tokio::test]
+async fn test_github_milestone_requests() {
+    let server = MockServer::start().await;
+
+    // due_on est un timestamp ISO 8601 complet
+    github_request("POST", "/repos/owner/repo/milestones")
+        .and(body_json(json!({
+            "title": "MVP",
+            "description": "First release",
+            "due_on": "2024-12-31T00:00:00Z",
+            "state": "open"
+        })))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(fixture("github/milestone_created.json")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    github_request("GET", "/repos/owner/repo/milestones")
+        .and(query_param("state", "all"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("github/milestones.json")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+    let remote = provider
+        .create_milestone(&Milestone {
+            name: "MVP".to_string(),
+            version: "0.1.0".to_string(),
+            deadline: "2024-12-31".to_string(),
+            description: "First release".to_string(),
+        })
+        .await
+        .unwrap();
+    // Le milestone est désigné par son numéro, l'id global est conservé à part
+    assert_eq!(
+        remote,
+        RemoteRef {
+            id: 4,
+            global_id: Some(1002605),
+            web_url: Some("https://github.com/owner/repo/milestone/4".to_string()),
+            node_id: Some("MDk6TWlsZXN0b25lMTAwMjYwNQ==".to_string()),
+        }
+    );
+
+    let milestones = provider.list_milestones().await.unwrap();
+    assert_eq!(milestones.len(), 2);
+    assert_eq!(milestones[0].id, 3);
+    assert_eq!(milestones[0].due_date.as_deref(), Some("2024-12-31"));
+    assert_eq!(milestones[1].state, IssueState::Closed);
+    assert_eq!(milestones[1].description, None);
+    assert_eq!(milestones[1].due_date, None);
+}
+
+#[tokio::test]
+async fn test_github_issue_requests() {
+    let server = MockServer::start().await;
+
+    github_request("POST", "/repos/owner/repo/issues")
+        .and(body_json(json!({
+            "title": "Fix login",
+            "body": "Steps to reproduce",
+            "milestone": null,
+            "labels": ["type::bug"],
+            "assignees": []
+        })))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(fixture("github/issue_created.json")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    github_request("GET", "/repos/owner/repo/issues/9")
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("github/issue.json")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+    let remote = provider
+        .create_issue(&IssueCreate {
+            title: "Fix login".to_string(),
+            description: "Steps to reproduce".to_string(),
+            labels: vec!["type::bug".to_string()],
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(remote.id, 12);
+    assert_eq!(remote.global_id, Some(4));
+    assert_eq!(
+        remote.web_url.as_deref(),
+        Some("https://github.com/owner/repo/issues/12")
+    );
+
+    let issue = provider.get_issue(9).await.unwrap();
+    assert_eq!(issue.id, 9);
+    assert_eq!(issue.title, "Setup CI");
+    assert_eq!(issue.description, "");
+    assert_eq!(issue.state, IssueState::Closed);
+    assert_eq!(issue.labels, ["type::chore"]);
+    assert_eq!(issue.milestone, None);
+    assert_eq!(issue.web_url, "https://github.com/owner/repo/issues/9");
+}
+
+#[tokio::test]
+async fn test_github_create_issue_resolves_milestone() {
+    let server = MockServer::start().await;
+
+    github_request("GET", "/repos/owner/repo/milestones")
+        .and(query_param("state", "all"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("github/milestones.json")))
+        // Les milestones ne sont listés qu'une fois pour toutes les issues
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // Les logins sont transmis tels quels, l'échéance et le poids sont ignorés
+    github_request("POST", "/repos/owner/repo/issues")
+        .and(body_json(json!({
+            "title": "Fix login",
+            "body": "Steps to reproduce",
+            "milestone": 3,
+            "labels": [],
+            "assignees": ["octocat", "hubot"]
+        })))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(fixture("github/issue_created.json")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+    let issue = |milestone: &str| IssueCreate {
+        title: "Fix login".to_string(),
+        description: "Steps to reproduce".to_string(),
+        milestone: Some(milestone.to_string()),
+        assignees: vec!["octocat".to_string(), "hubot".to_string()],
+        due_date: Some("2024-12-31".to_string()),
+        weight: Some(3),
+        ..Default::default()
+    };
+
+    provider.create_issue(&issue("MVP")).await.unwrap();
+
+    // Un milestone inconnu est signalé avant toute création
+    let error = provider.create_issue(&issue("2.0")).await.unwrap_err();
+    assert!(matches!(error, ProviderError::Config(message) if message.contains("2.0")));
+}
+
+#[tokio::test]
+async fn test_github_project_issue_uses_resolved_milestone() {
+    let server = MockServer::start().await;
+
+    // Le numéro retourné par `create_milestone` évite de relister les milestones
+    github_request("GET", "/repos/owner/repo/milestones")
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("github/milestones.json")))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    github_request("POST", "/repos/owner/repo/issues")
+        .and(body_partial_json(
+            json!({"title": "Setup CI", "milestone": 7}),
+        ))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(fixture("github/issue_created.json")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let project = sample_project();
+    provider(&server)
+        .create_project_issue(&project.issues[0], &project.milestones[0], 7)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_github_list_issues_skips_pull_requests() {
+    let server = MockServer::start().await;
+
+    github_request("GET", "/repos/owner/repo/milestones")
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("github/milestones.json")))
+        .mount(&server)
+        .await;
+
+    // GitHub filtre par numéro de milestone
+    github_request("GET", "/repos/owner/repo/issues")
+        .and(query_param("state", "open"))
+        .and(query_param("labels", "type::feature,backend"))
+        .and(query_param("milestone", "3"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("github/issues.json")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+    let issues = provider
+        .list_issues(&IssueFilter {
+            state: Some(IssueState::Open),
+            labels: vec!["type::feature".to_string(), "backend".to_string()],
+            milestone: Some("MVP".to_string()),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].id, 7);
+    assert_eq!(issues[0].milestone.as_deref(), Some("MVP"));
+    assert!(issues[0]
+        .description
+        .contains("<!-- repo_manager:issue Write API -->"));
+
+    // Milestone inconnu : aucune requête sur les issues
+    let issues = provider
+        .list_issues(&IssueFilter {
+            milestone: Some("Unknown".to_string()),
+            ..IssueFilter::default()
+        })
+        .await
+        .unwrap();
+    assert!(issues.is_empty());
+}
+
+#[tokio::test]
+async fn test_github_issue_link_uses_dependencies() {
+    let server = MockServer::start().await;
+
+    github_request("GET", "/repos/owner/repo/issues/12/dependencies/blocked_by")
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("github/blocked_by.json")))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    // L'API attend l'identifiant global de l'issue bloquante
+    github_request("GET", "/repos/owner/repo/issues/9")
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("github/issue.json")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    github_request(
+        "POST",
+        "/repos/owner/repo/issues/12/dependencies/blocked_by",
+    )
+    .and(body_json(json!({"issue_id": 3})))
+    .respond_with(ResponseTemplate::new(201).set_body_json(fixture("github/issue_created.json")))
+    .expect(1)
+    .mount(&server)
+    .await;
+
+    let provider = provider(&server);
+    provider
+        .create_issue_link(12, 9, LinkKind::BlockedBy)
+        .await
+        .unwrap();
+    // #7 bloque déjà #12 : aucune requête POST
+    provider
+        .create_issue_link(12, 7, LinkKind::BlockedBy)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_github_issue_link_falls_back_to_comment() {
+    let server = MockServer::start().await;
+
+    // Instance sans API des dépendances
+    github_request("GET", "/repos/owner/repo/issues/12/dependencies/blocked_by")
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({"message": "Not Found"})))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    github_request("GET", "/repos/owner/repo/issues/12/comments")
+        .and(query_param_is_missing("page"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("github/comments.json")))
+        .mount(&server)
+        .await;
+
+    github_request("POST", "/repos/owner/repo/issues/12/comments")
+        .and(body_json(json!({"body": "Depends on #9"})))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(fixture("github/comment_created.json")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+    provider
+        .create_issue_link(12, 9, LinkKind::BlockedBy)
+        .await
+        .unwrap();
+    // Le commentaire « Depends on #7 » existe déjà : aucune requête POST
+    provider
+        .create_issue_link(12, 7, LinkKind::BlockedBy)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_github_typed_links() {
+    let server = MockServer::start().await;
+
+    // « #12 bloque #9 » devient « #9 bloquée par #12 »
+    github_request("GET", "/repos/owner/repo/issues/9/dependencies/blocked_by")
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .mount(&server)
+        .await;
+
+    github_request("GET", "/repos/owner/repo/issues/12")
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(fixture("github/issue_created.json")),
+        )
+        .mount(&server)
+        .await;
+
+    github_request("POST", "/repos/owner/repo/issues/9/dependencies/blocked_by")
+        .and(body_json(json!({"issue_id": 4})))
+        .respond_with(ResponseTemplate::new(201).set_body_json(fixture("github/issue.json")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // « #12 parent de #7 » : #7 est déjà une sous-issue
+    github_request("GET", "/repos/owner/repo/issues/12/sub_issues")
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("github/sub_issues.json")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // GitHub n'a pas de lien « relates to » : il est noté en commentaire
+    github_request("GET", "/repos/owner/repo/issues/12/comments")
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("github/comments.json")))
+        .mount(&server)
+        .await;
+
+    github_request("POST", "/repos/owner/repo/issues/12/comments")
+        .and(body_json(json!({"body": "Related to #9"})))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(fixture("github/comment_created.json")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+    provider
+        .create_issue_link(12, 9, LinkKind::Blocks)
+        .await
+        .unwrap();
+    provider
+        .create_issue_link(12, 7, LinkKind::Parent)
+        .await
+        .unwrap();
+    provider
+        .create_issue_link(12, 9, LinkKind::RelatesTo)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_github_sub_issues() {
+    let server = MockServer::start().await;
+
+    github_request("GET", "/repos/owner/repo/issues/12/sub_issues")
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("github/sub_issues.json")))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    github_request("GET", "/repos/owner/repo/issues/9")
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("github/issue.json")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    github_request("POST", "/repos/owner/repo/issues/12/sub_issues")
+        .and(body_json(json!({"sub_issue_id": 3})))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(fixture("github/issue_created.json")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+    provider
+        .create_issue_link(12, 9, LinkKind::Parent)
+        .await
+        .unwrap();
+    // #7 est déjà une sous-issue de #12
+    provider
+        .create_issue_link(12, 7, LinkKind::Parent)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_github_sub_issue_falls_back_to_task_list() {
+    let server = MockServer::start().await;
+
+    github_request("GET", "/repos/owner/repo/issues/12/sub_issues")
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({"message": "Not Found"})))
+        .mount(&server)
+        .await;
+
+    let mut parent = fixture("github/issue_created.json");
+    parent["body"] = json!("Steps to reproduce\n- [x] #7\n");
+    github_request("GET", "/repos/owner/repo/issues/12")
+        .respond_with(ResponseTemplate::new(200).set_body_json(parent))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    github_request("PATCH", "/repos/owner/repo/issues/12")
+        .and(body_json(
+            json!({"body": "Steps to reproduce\n- [x] #7\n- [ ] #9"}),
+        ))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(fixture("github/issue_created.json")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+    provider
+        .create_issue_link(12, 9, LinkKind::Parent)
+        .await
+        .unwrap();
+    // #7 figure déjà dans la liste de tâches
+    provider
+        .create_issue_link(12, 7, LinkKind::Parent)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_github_error_response_is_parsed() {
+    let server = MockServer::start().await;
+
+    github_request("POST", "/repos/owner/repo/labels")
+        .respond_with(ResponseTemplate::new(422).set_body_json(json!({
+            "message": "Validation Failed",
+            "errors": [{"resource": "Label", "code": "already_exists", "field": "name"}],
+            "documentation_url": "https://docs.github.com/rest/issues/labels#create-a-label",
+            "status": "422"
+        })))
+        .mount(&server)
+        .await;
+
+    let error = provider(&server)
+        .create_label(&label("type::bug", "#d73a4a", None))
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.status(), Some(422));
+    assert!(error.to_string().contains("already_exists"));
+}
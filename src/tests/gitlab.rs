@@ -0,0 +1,391 @@
+use serde_json::json;
+use wiremock::matchers::{body_json, header, method, path, query_param, query_param_is_missing};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use super::{fixture, label, mock_config};
+use crate::error::ProviderError;
+use crate::models::common::{IssueCreate, IssueFilter, IssueState, LinkKind, Milestone};
+use crate::providers::GitLabProvider;
+use crate::traits::repository::RepositoryProvider;
+
+const PROJECT: &str = "/projects/group%2Fproject";
+
+fn provider(server: &MockServer) -> GitLabProvider {
+    GitLabProvider::new(mock_config(server, "group/project")).unwrap()
+}
+
+/// Requête authentifiée sur une ressource du projet
+fn gitlab_request(verb: &str, resource: &str) -> wiremock::MockBuilder {
+    Mock::given(method(verb))
+        .and(path(format!("{}/{}", PROJECT, resource)))
+        .and(header("private-token", "test_token"))
+}
+
+#[tokio::test]
+async fn test_gitlab_label_requests() {
+    let server = MockServer::start().await;
+
+    // GitLab attend la couleur avec le #
+    gitlab_request("POST", "labels")
+        .and(body_json(json!({
+            "name": "type::bug",
+            "color": "#d9534f",
+            "description": "Bug report"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(fixture("gitlab/label.json")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // Une description absente est envoyée vide pour effacer l'ancienne
+    gitlab_request("PUT", "labels/type%3A%3Abug")
+        .and(body_json(json!({"color": "#ff0000", "description": ""})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("gitlab/label.json")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    gitlab_request("DELETE", "labels/type%3A%3Abug")
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+    provider
+        .create_label(&label("type::bug", "#d9534f", Some("Bug report")))
+        .await
+        .unwrap();
+    provider
+        .update_label(&label("type::bug", "#ff0000", None))
+        .await
+        .unwrap();
+    provider.delete_label("type::bug").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_gitlab_list_labels() {
+    let server = MockServer::start().await;
+
+    gitlab_request("GET", "labels")
+        .and(query_param("per_page", "100"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("x-page", "1")
+                .insert_header("x-next-page", "")
+                .set_body_json(fixture("gitlab/labels.json")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let labels = provider(&server).list_labels().await.unwrap();
+
+    assert_eq!(labels.len(), 2);
+    assert_eq!(labels[0].name, "type::bug");
+    assert_eq!(labels[0].color, "#d9534f");
+    assert_eq!(labels[0].description.as_deref(), Some("Bug report"));
+    assert_eq!(labels[1].color, "#FF0000");
+    assert_eq!(labels[1].description, None);
+}
+
+#[tokio::test]
+async fn test_gitlab_milestone_requests() {
+    let server = MockServer::start().await;
+
+    // due_date reste au format AAAA-MM-JJ
+    gitlab_request("POST", "milestones")
+        .and(body_json(json!({
+            "title": "MVP",
+            "description": "First release",
+            "due_date": "2024-12-31"
+        })))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(fixture("gitlab/milestone_created.json")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    gitlab_request("GET", "milestones")
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("gitlab/milestones.json")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+    let remote = provider
+        .create_milestone(&Milestone {
+            name: "MVP".to_string(),
+            version: "0.1.0".to_string(),
+            deadline: "2024-12-31".to_string(),
+            description: "First release".to_string(),
+        })
+        .await
+        .unwrap();
+    // GitLab identifie les milestones par leur id global
+    assert_eq!(remote.id, 13);
+    assert_eq!(
+        remote.web_url.as_deref(),
+        Some("https://gitlab.example.com/group/project/-/milestones/4")
+    );
+
+    let milestones = provider.list_milestones().await.unwrap();
+    assert_eq!(milestones.len(), 2);
+    assert_eq!(milestones[0].id, 12);
+    assert_eq!(milestones[0].state, IssueState::Open);
+    assert_eq!(milestones[0].due_date.as_deref(), Some("2024-12-31"));
+    assert_eq!(milestones[1].state, IssueState::Closed);
+    assert_eq!(milestones[1].due_date, None);
+}
+
+#[tokio::test]
+async fn test_gitlab_issue_requests() {
+    let server = MockServer::start().await;
+
+    gitlab_request("POST", "issues")
+        .and(body_json(json!({
+            "title": "Fix login",
+            "description": "Steps to reproduce",
+            "labels": ["type::bug"]
+        })))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(fixture("gitlab/issue_created.json")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    gitlab_request("GET", "issues/5")
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("gitlab/issue.json")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+    let remote = provider
+        .create_issue(&IssueCreate {
+            title: "Fix login".to_string(),
+            description: "Steps to reproduce".to_string(),
+            labels: vec!["type::bug".to_string()],
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(remote.id, 10);
+    assert_eq!(remote.global_id, Some(80));
+    assert_eq!(
+        remote.web_url.as_deref(),
+        Some("https://gitlab.example.com/group/project/-/issues/10")
+    );
+
+    let issue = provider.get_issue(5).await.unwrap();
+    // L'identifiant retenu est l'iid, propre au projet
+    assert_eq!(issue.id, 5);
+    assert_eq!(issue.title, "Setup CI");
+    assert_eq!(issue.description, "## Objectif\nRun the tests");
+    assert_eq!(issue.state, IssueState::Closed);
+    assert_eq!(issue.labels, ["type::chore"]);
+    assert_eq!(issue.milestone.as_deref(), Some("MVP"));
+    assert_eq!(
+        issue.web_url,
+        "https://gitlab.example.com/group/project/-/issues/5"
+    );
+}
+
+#[tokio::test]
+async fn test_gitlab_create_issue_resolves_milestone_and_assignees() {
+    let server = MockServer::start().await;
+
+    gitlab_request("GET", "milestones")
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("gitlab/milestones.json")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    for (username, users) in [
+        ("jdoe", json!([{"id": 42, "username": "jdoe"}])),
+        ("ghost", json!([])),
+    ] {
+        Mock::given(method("GET"))
+            .and(path("/users"))
+            .and(query_param("username", username))
+            .respond_with(ResponseTemplate::new(200).set_body_json(users))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    gitlab_request("POST", "issues")
+        .and(body_json(json!({
+            "title": "Fix login",
+            "description": "Steps to reproduce",
+            "labels": [],
+            "milestone_id": 12,
+            "assignee_ids": [42],
+            "due_date": "2024-12-31",
+            "weight": 3
+        })))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(fixture("gitlab/issue_created.json")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+    let mut issue = IssueCreate {
+        title: "Fix login".to_string(),
+        description: "Steps to reproduce".to_string(),
+        milestone: Some("MVP".to_string()),
+        assignees: vec!["jdoe".to_string()],
+        due_date: Some("2024-12-31".to_string()),
+        weight: Some(3),
+        ..Default::default()
+    };
+    provider.create_issue(&issue).await.unwrap();
+
+    // Un utilisateur inconnu est signalé avant toute création
+    issue.milestone = None;
+    issue.assignees = vec!["ghost".to_string()];
+    let error = provider.create_issue(&issue).await.unwrap_err();
+    assert!(matches!(error, ProviderError::Config(message) if message.contains("ghost")));
+}
+
+#[tokio::test]
+async fn test_gitlab_list_issues_follows_page_headers() {
+    let server = MockServer::start().await;
+
+    gitlab_request("GET", "issues")
+        .and(query_param("state", "opened"))
+        .and(query_param("labels", "backend"))
+        .and(query_param("milestone", "MVP"))
+        .and(query_param_is_missing("page"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("x-page", "1")
+                .insert_header("x-next-page", "2")
+                .insert_header("x-total-pages", "2")
+                .set_body_json(fixture("gitlab/issues_page1.json")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // Les filtres sont conservés sur la page suivante
+    gitlab_request("GET", "issues")
+        .and(query_param("state", "opened"))
+        .and(query_param("page", "2"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("x-page", "2")
+                .insert_header("x-next-page", "")
+                .insert_header("x-total-pages", "2")
+                .set_body_json(fixture("gitlab/issues_page2.json")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let issues = provider(&server)
+        .list_issues(&IssueFilter {
+            state: Some(IssueState::Open),
+            labels: vec!["backend".to_string()],
+            milestone: Some("MVP".to_string()),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(issues.len(), 2);
+    assert_eq!(issues[0].id, 6);
+    assert_eq!(issues[0].labels, ["type::feature", "backend"]);
+    assert_eq!(issues[0].milestone.as_deref(), Some("MVP"));
+    assert_eq!(issues[1].description, "");
+    assert_eq!(issues[1].state, IssueState::Closed);
+}
+
+#[tokio::test]
+async fn test_gitlab_issue_link() {
+    let server = MockServer::start().await;
+
+    gitlab_request("POST", "issues/10/links")
+        .and(query_param("target_project_id", "group/project"))
+        .and(query_param("target_issue_iid", "5"))
+        .and(query_param("link_type", "is_blocked_by"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(fixture("gitlab/issue_link.json")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // Lien déjà existant
+    gitlab_request("POST", "issues/10/links")
+        .and(query_param("target_issue_iid", "6"))
+        .respond_with(ResponseTemplate::new(409).set_body_json(json!({
+            "message": "Issue(s) already assigned"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+    provider
+        .create_issue_link(10, 5, LinkKind::BlockedBy)
+        .await
+        .unwrap();
+    provider
+        .create_issue_link(10, 6, LinkKind::BlockedBy)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_gitlab_typed_links() {
+    let server = MockServer::start().await;
+
+    for (target, link_type) in [("5", "blocks"), ("6", "relates_to"), ("7", "relates_to")] {
+        gitlab_request("POST", "issues/10/links")
+            .and(query_param("target_issue_iid", target))
+            .and(query_param("link_type", link_type))
+            .respond_with(
+                ResponseTemplate::new(201).set_body_json(fixture("gitlab/issue_link.json")),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let provider = provider(&server);
+    provider
+        .create_issue_link(10, 5, LinkKind::Blocks)
+        .await
+        .unwrap();
+    provider
+        .create_issue_link(10, 6, LinkKind::RelatesTo)
+        .await
+        .unwrap();
+    // Les liens parent/enfant n'existent pas entre issues GitLab
+    provider
+        .create_issue_link(10, 7, LinkKind::Parent)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_gitlab_error_response_is_parsed() {
+    let server = MockServer::start().await;
+
+    gitlab_request("POST", "labels")
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "message": {"color": ["must be a valid color code"]}
+        })))
+        .mount(&server)
+        .await;
+
+    let error = provider(&server)
+        .create_label(&label("type::bug", "red", None))
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.status(), Some(400));
+    assert!(error.to_string().contains("must be a valid color code"));
+}
@@ -1,66 +1,224 @@
 use repo_manager::{
-    error::ProviderError,
+    engine::setup_project,
     models::common::*,
-    providers::{create_provider, ProviderConfig, ProviderType},
+    providers::{create_provider, ProviderConfig, ProviderType, RetryPolicy},
+    state::ProjectState,
+    traits::repository::RepositoryProvider,
 };
-use std::env;
+use serde_json::{json, Value};
+use std::path::Path;
+use wiremock::matchers::{body_partial_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
-async fn setup_test_provider(
-) -> Result<Box<dyn repo_manager::traits::repository::RepositoryProvider>, ProviderError> {
-    dotenv::dotenv().ok();
+/// Réponse enregistrée d'une forge, lue dans `src/tests/fixtures`
+fn fixture(name: &str) -> Value {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src/tests/fixtures")
+        .join(name);
+    let content = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Missing fixture {}: {}", path.display(), e));
 
-    let config = ProviderConfig {
-        api_url: env::var("REPO_API_URL").expect("REPO_API_URL must be set"),
-        token: env::var("REPO_TOKEN").expect("REPO_TOKEN must be set"),
-        repository: env::var("REPO_PATH").expect("REPO_PATH must be set"),
-    };
+    serde_json::from_str(&content).unwrap()
+}
+
+/// Provider pointé sur le serveur de fixtures
+fn setup_test_provider(
+    provider_type: ProviderType,
+    server: &MockServer,
+    repository: &str,
+) -> Box<dyn RepositoryProvider> {
+    let config = ProviderConfig::builder()
+        .api_url(server.uri())
+        .token("test_token")
+        .repository(repository)
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .expect("Invalid test config");
 
-    create_provider(ProviderType::GitHub, config)
+    create_provider(provider_type, config).expect("Failed to create provider")
 }
 
-#[tokio::test]
-#[ignore]
-async fn test_full_project_setup() {
-    let provider = setup_test_provider()
-        .await
-        .expect("Failed to create provider");
+fn test_project() -> Project {
+    let issue = |title: &str, dependencies: &[&str]| ProjectIssue {
+        title: title.to_string(),
+        milestone: "0.1.0".to_string(),
+        estimate: "1d".to_string(),
+        sprint: 1,
+        dependencies: dependencies.iter().map(|d| d.to_string()).collect(),
+        links: Vec::new(),
+        labels: vec!["test".to_string()],
+        description: IssueDescription {
+            sections: vec![Section {
+                title: "## Objectif".to_string(),
+                content: vec!["Test objective".to_string()],
+            }],
+        },
+    };
 
-    let project = Project {
+    Project {
         name: "Test Project".to_string(),
         version: "0.1.0".to_string(),
         milestones: vec![Milestone {
-            name: "v0.1.0".to_string(),
+            name: "MVP".to_string(),
             version: "0.1.0".to_string(),
             deadline: "2024-12-31".to_string(),
-            description: "Test milestone".to_string(),
-        }],
-        issues: vec![ProjectIssue {
-            title: "Test Issue".to_string(),
-            milestone: "0.1.0".to_string(),
-            estimate: "1d".to_string(),
-            sprint: 1,
-            dependencies: vec![],
-            labels: vec!["test".to_string()],
-            description: IssueDescription {
-                sections: vec![Section {
-                    title: "## Objectif".to_string(),
-                    content: vec!["Test objective".to_string()],
-                }],
-            },
+            description: "First release".to_string(),
         }],
-    };
+        issues: vec![issue("Setup CI", &[]), issue("Fix login", &["Setup CI"])],
+    }
+}
+
+#[tokio::test]
+async fn test_github_full_project_setup() {
+    let server = MockServer::start().await;
+
+    for resource in ["milestones", "issues"] {
+        Mock::given(method("GET"))
+            .and(path(format!("/repos/owner/repo/{}", resource)))
+            .and(query_param("state", "all"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .mount(&server)
+            .await;
+    }
+
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/milestones"))
+        .and(header("x-github-api-version", "2022-11-28"))
+        .and(body_partial_json(json!({"due_on": "2024-12-31T00:00:00Z"})))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(fixture("github/milestone_created.json")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    for (number, title) in [(9, "Setup CI"), (12, "Fix login")] {
+        let mut response = fixture("github/issue_created.json");
+        response["number"] = json!(number);
+
+        Mock::given(method("POST"))
+            .and(path("/repos/owner/repo/issues"))
+            .and(body_partial_json(json!({
+                "title": title,
+                "milestone": 4,
+                "labels": ["test"]
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(response))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/issues/12/dependencies/blocked_by"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/issues/9"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("github/issue.json")))
+        .mount(&server)
+        .await;
 
-    let result = provider.setup_project(&project).await;
-    assert!(result.is_ok(), "Project setup should succeed");
+    // « Fix login » est bloquée par « Setup CI », désignée par son id global
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/issues/12/dependencies/blocked_by"))
+        .and(body_partial_json(json!({"issue_id": 3})))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(fixture("github/issue_created.json")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = setup_test_provider(ProviderType::GitHub, &server, "owner/repo");
+    let mut state = ProjectState::default();
+    setup_project(provider.as_ref(), &test_project(), &mut state)
+        .await
+        .expect("Project setup should succeed");
+
+    assert_eq!(state.milestone_id("0.1.0"), Some(4));
+    assert_eq!(state.issue_id("Fix login"), Some(12));
+    assert!(state.has_link("Fix login", "Setup CI", LinkKind::BlockedBy));
 }
 
 #[tokio::test]
-#[ignore]
-async fn test_label_operations() {
-    let provider = setup_test_provider()
+async fn test_gitlab_full_project_setup() {
+    let server = MockServer::start().await;
+    let project = "/projects/group%2Fproject";
+
+    for resource in ["milestones", "issues"] {
+        Mock::given(method("GET"))
+            .and(path(format!("{}/{}", project, resource)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .mount(&server)
+            .await;
+    }
+
+    Mock::given(method("POST"))
+        .and(path(format!("{}/milestones", project)))
+        .and(header("private-token", "test_token"))
+        .and(body_partial_json(json!({"due_date": "2024-12-31"})))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(fixture("gitlab/milestone_created.json")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    for (iid, title) in [(5, "Setup CI"), (10, "Fix login")] {
+        let mut response = fixture("gitlab/issue_created.json");
+        response["iid"] = json!(iid);
+
+        Mock::given(method("POST"))
+            .and(path(format!("{}/issues", project)))
+            .and(body_partial_json(json!({
+                "title": title,
+                "milestone_id": 13,
+                "labels": ["test"]
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(response))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    Mock::given(method("POST"))
+        .and(path(format!("{}/issues/10/links", project)))
+        .and(query_param("target_project_id", "group/project"))
+        .and(query_param("target_issue_iid", "5"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(fixture("gitlab/issue_link.json")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = setup_test_provider(ProviderType::GitLab, &server, "group/project");
+    let mut state = ProjectState::default();
+    setup_project(provider.as_ref(), &test_project(), &mut state)
         .await
-        .expect("Failed to create provider");
+        .expect("Project setup should succeed");
+
+    assert_eq!(state.milestone_id("0.1.0"), Some(13));
+    assert_eq!(state.issue_id("Fix login"), Some(10));
+    assert!(state.has_link("Fix login", "Setup CI", LinkKind::BlockedBy));
+}
+
+#[tokio::test]
+async fn test_label_operations() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/labels"))
+        .and(body_partial_json(
+            json!({"name": "test_label", "color": "ff0000"}),
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(fixture("github/label.json")))
+        .expect(1)
+        .mount(&server)
+        .await;
 
+    let provider = setup_test_provider(ProviderType::GitHub, &server, "owner/repo");
     let label = Label {
         name: "test_label".to_string(),
         color: "#ff0000".to_string(),
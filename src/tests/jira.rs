@@ -0,0 +1,421 @@
+use serde_json::json;
+use wiremock::matchers::{body_json, body_partial_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use super::{label, mock_config, sample_project};
+use crate::engine::setup_project;
+use crate::error::ProviderError;
+use crate::models::common::{IssueCreate, IssueFilter, IssueState, LinkKind, Milestone};
+use crate::providers::JiraProvider;
+use crate::state::ProjectState;
+use crate::sync::sync_labels;
+use crate::traits::repository::RepositoryProvider;
+
+fn provider(server: &MockServer, repository: &str) -> JiraProvider {
+    JiraProvider::new(mock_config(server, repository)).unwrap()
+}
+
+#[tokio::test]
+async fn test_jira_description_is_converted_to_adf() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/rest/api/3/issue"))
+        // base64("me@example.com:api_token")
+        .and(header(
+            "authorization",
+            "Basic bWVAZXhhbXBsZS5jb206YXBpX3Rva2Vu",
+        ))
+        .and(body_partial_json(json!({
+            "fields": {
+                "project": {"key": "PROJ"},
+                "summary": "Write API",
+                "issuetype": {"name": "Task"},
+                "labels": ["type::feature", "needs_review"],
+                "description": {
+                    "type": "doc",
+                    "version": 1,
+                    "content": [
+                        {
+                            "type": "heading",
+                            "attrs": {"level": 2},
+                            "content": [{"type": "text", "text": "Objectif"}]
+                        },
+                        {
+                            "type": "paragraph",
+                            "content": [{"type": "text", "text": "Expose the API"}]
+                        },
+                        {
+                            "type": "taskList",
+                            "attrs": {"localId": "list-3"},
+                            "content": [
+                                {
+                                    "type": "taskItem",
+                                    "attrs": {"localId": "task-1", "state": "TODO"},
+                                    "content": [{"type": "text", "text": "Routes"}]
+                                },
+                                {
+                                    "type": "taskItem",
+                                    "attrs": {"localId": "task-2", "state": "DONE"},
+                                    "content": [{"type": "text", "text": "Schema"}]
+                                }
+                            ]
+                        },
+                        {
+                            "type": "bulletList",
+                            "content": [{
+                                "type": "listItem",
+                                "content": [{
+                                    "type": "paragraph",
+                                    "content": [{"type": "text", "text": "Docs"}]
+                                }]
+                            }]
+                        }
+                    ]
+                }
+            }
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "id": "10001",
+            "key": "PROJ-1"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // Jira Cloud : `email:api_token` envoyé en Basic
+    let mut config = mock_config(&server, "PROJ");
+    config.token = "me@example.com:api_token".to_string();
+
+    JiraProvider::new(config)
+        .unwrap()
+        .create_issue(&IssueCreate {
+            title: "Write API".to_string(),
+            description: "## Objectif\nExpose the API\n\n- [ ] Routes\n- [x] Schema\n- Docs\n"
+                .to_string(),
+            labels: vec!["type::feature".to_string(), "needs review".to_string()],
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_jira_setup_project_uses_versions_sprints_and_links() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/rest/api/3/project/PROJ/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/rest/api/3/search/jql"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"issues": []})))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/rest/api/3/project/PROJ"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"id": "10000"})))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/rest/api/3/version"))
+        .and(body_json(json!({
+            "name": "MVP",
+            "description": "First release",
+            "releaseDate": "2024-12-31",
+            "projectId": 10000
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "id": "20000",
+            "name": "MVP"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/rest/agile/1.0/board/7/sprint"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "values": [],
+            "isLast": true
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/rest/agile/1.0/sprint"))
+        .and(body_json(json!({"name": "Sprint 1", "originBoardId": 7})))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "id": 30,
+            "name": "Sprint 1"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    for (id, key, title) in [
+        ("101", "PROJ-1", "Setup CI"),
+        ("102", "PROJ-2", "Write API"),
+    ] {
+        Mock::given(method("POST"))
+            .and(path("/rest/api/3/issue"))
+            .and(body_partial_json(json!({
+                "fields": {"summary": title, "fixVersions": [{"id": "20000"}]}
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                "id": id,
+                "key": key
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/rest/agile/1.0/sprint/30/issue"))
+            .and(body_json(json!({"issues": [key]})))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    Mock::given(method("GET"))
+        .and(path("/rest/api/3/issue/102"))
+        .and(query_param("fields", "issuelinks"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "fields": {"issuelinks": []}
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/rest/api/3/issueLink"))
+        .and(body_json(json!({
+            "type": {"name": "Blocks"},
+            "outwardIssue": {"id": "101"},
+            "inwardIssue": {"id": "102"}
+        })))
+        .respond_with(ResponseTemplate::new(201))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut state = ProjectState::default();
+    setup_project(&provider(&server, "PROJ/7"), &sample_project(), &mut state)
+        .await
+        .unwrap();
+
+    assert_eq!(state.milestone_id("0.1.0"), Some(20000));
+    assert_eq!(state.issue_id("Setup CI"), Some(101));
+    assert!(state.has_link("Write API", "Setup CI", LinkKind::BlockedBy));
+}
+
+#[tokio::test]
+async fn test_jira_list_issues_follows_page_token() {
+    let server = MockServer::start().await;
+
+    let issue = |id: &str, key: &str, status: &str| {
+        json!({
+            "id": id,
+            "key": key,
+            "fields": {
+                "summary": format!("Issue {}", key),
+                "description": {
+                    "type": "doc",
+                    "version": 1,
+                    "content": [
+                        {"type": "heading", "attrs": {"level": 2}, "content": [{"type": "text", "text": "Objectif"}]},
+                        {"type": "paragraph", "content": [{"type": "text", "text": "Do it"}]}
+                    ]
+                },
+                "status": {"statusCategory": {"key": status}},
+                "labels": ["backend"],
+                "fixVersions": [{"name": "MVP"}]
+            }
+        })
+    };
+
+    Mock::given(method("GET"))
+        .and(path("/rest/api/3/search/jql"))
+        .and(query_param(
+            "jql",
+            "project = \"PROJ\" AND labels = \"backend\" AND fixVersion = \"MVP\" ORDER BY created ASC",
+        ))
+        .and(query_param("nextPageToken", "page-2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "issues": [issue("11", "PROJ-2", "done")]
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/rest/api/3/search/jql"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "issues": [issue("10", "PROJ-1", "new")],
+            "nextPageToken": "page-2"
+        })))
+        .mount(&server)
+        .await;
+
+    let issues = provider(&server, "PROJ")
+        .list_issues(&IssueFilter {
+            labels: vec!["backend".to_string()],
+            milestone: Some("MVP".to_string()),
+            ..IssueFilter::default()
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(issues.len(), 2);
+    assert_eq!(issues[0].id, 10);
+    assert_eq!(issues[0].description, "Objectif\nDo it");
+    assert_eq!(issues[0].state, IssueState::Open);
+    assert_eq!(issues[1].state, IssueState::Closed);
+    assert_eq!(issues[1].milestone.as_deref(), Some("MVP"));
+    assert!(issues[1].web_url.ends_with("/browse/PROJ-2"));
+}
+
+#[tokio::test]
+async fn test_jira_label_sync_matches_normalized_names() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/rest/api/3/label"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "values": ["good_first_issue", "stale"],
+            "isLast": true
+        })))
+        .mount(&server)
+        .await;
+
+    // `prune` ne doit pas tenter de supprimer "stale"
+    let desired = [label("good first issue", "#7057ff", None)];
+    let report = sync_labels(&provider(&server, "PROJ"), &desired, true)
+        .await
+        .unwrap();
+
+    assert!(report.created.is_empty());
+    assert!(report.deleted.is_empty());
+    assert!(report.failed.is_empty());
+    assert_eq!(report.unchanged, ["good_first_issue"]);
+}
+
+#[tokio::test]
+async fn test_jira_project_id_is_resolved_once() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/rest/api/3/project/PROJ"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"id": "10000"})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/rest/api/3/version"))
+        .and(body_partial_json(json!({"projectId": 10000})))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "id": "20000",
+            "name": "MVP"
+        })))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server, "PROJ");
+    for name in ["MVP", "Beta"] {
+        provider
+            .create_milestone(&Milestone {
+                name: name.to_string(),
+                version: "0.1.0".to_string(),
+                deadline: "2024-12-31".to_string(),
+                description: String::new(),
+            })
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+async fn test_jira_relates_link_is_not_duplicated_in_reverse() {
+    let server = MockServer::start().await;
+
+    // L'issue 102 déclare déjà "relates to" 101, dans l'autre sens
+    Mock::given(method("GET"))
+        .and(path("/rest/api/3/issue/102"))
+        .and(query_param("fields", "issuelinks"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "fields": {"issuelinks": [{
+                "type": {"name": "Relates"},
+                "outwardIssue": {"id": "101"}
+            }]}
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/rest/api/3/issueLink"))
+        .respond_with(ResponseTemplate::new(201))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    provider(&server, "PROJ")
+        .create_issue_link(102, 101, LinkKind::RelatesTo)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_jira_create_issue_sets_fix_version_and_due_date() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/rest/api/3/project/PROJ/versions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {"id": "20000", "name": "MVP", "releaseDate": "2024-12-31", "released": false}
+        ])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/rest/api/3/issue"))
+        .and(body_partial_json(json!({
+            "fields": {"fixVersions": [{"id": "20000"}], "duedate": "2024-12-15"}
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "id": "10001",
+            "key": "PROJ-1"
+        })))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    // Les versions ne sont listées qu'une fois pour toutes les issues
+    let provider = provider(&server, "PROJ");
+    let mut issue = IssueCreate {
+        title: "Fix login".to_string(),
+        milestone: Some("MVP".to_string()),
+        due_date: Some("2024-12-15".to_string()),
+        ..Default::default()
+    };
+    provider.create_issue(&issue).await.unwrap();
+    provider.create_issue(&issue).await.unwrap();
+
+    // Jira Cloud n'assigne pas par nom d'utilisateur : rien n'est créé
+    issue.assignees = vec!["jdoe".to_string()];
+    let error = provider.create_issue(&issue).await.unwrap_err();
+    assert!(matches!(error, ProviderError::Config(message) if message.contains("jdoe")));
+
+    // Ni champ de poids standard
+    issue.assignees.clear();
+    issue.weight = Some(3);
+    let error = provider.create_issue(&issue).await.unwrap_err();
+    assert!(matches!(error, ProviderError::Config(message) if message.contains("weight")));
+}
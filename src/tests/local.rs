@@ -0,0 +1,205 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use super::{label, sample_project};
+use crate::engine::setup_project;
+use crate::error::ProviderError;
+use crate::models::common::{IssueCreate, IssueFilter, LinkKind};
+use crate::providers::LocalProvider;
+use crate::state::ProjectState;
+use crate::traits::repository::RepositoryProvider;
+
+fn temp_root(name: &str) -> PathBuf {
+    let root = env::temp_dir().join(format!(
+        "repo_manager_local_{}_{}",
+        name,
+        std::process::id()
+    ));
+    let _ = fs::remove_dir_all(&root);
+    root
+}
+
+#[tokio::test]
+async fn test_local_setup_project_writes_markdown_files() {
+    let root = temp_root("setup");
+    let provider = LocalProvider::new(&root);
+
+    let mut state = ProjectState::default();
+    setup_project(&provider, &sample_project(), &mut state)
+        .await
+        .unwrap();
+
+    assert_eq!(state.milestone_id("0.1.0"), Some(1));
+    assert_eq!(state.issue_id("Setup CI"), Some(1));
+    assert_eq!(state.issue_id("Write API"), Some(2));
+
+    let content = fs::read_to_string(root.join("issues/0002-write-api.md")).unwrap();
+    assert!(content.starts_with(
+        "---\nid: 2\ntitle: \"Write API\"\nstate: open\nmilestone: \"MVP\"\nestimate: \"1d\"\nsprint: 1\nlabels:\ndepends_on:\n  - 1\n---\n"
+    ));
+    assert!(content.contains("<!-- repo_manager:issue Write API -->"));
+
+    let milestones = provider.list_milestones().await.unwrap();
+    assert_eq!(milestones[0].title, "MVP");
+    assert_eq!(milestones[0].due_date.as_deref(), Some("2024-12-31"));
+
+    // Nouvel état : les issues sont retrouvées grâce au marqueur, rien n'est recréé
+    let mut state = ProjectState::default();
+    setup_project(&provider, &sample_project(), &mut state)
+        .await
+        .unwrap();
+    assert_eq!(
+        provider
+            .list_issues(&IssueFilter::default())
+            .await
+            .unwrap()
+            .len(),
+        2
+    );
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[tokio::test]
+async fn test_local_labels_and_issue_filters() {
+    let root = temp_root("labels");
+    let provider = LocalProvider::new(&root);
+
+    provider
+        .create_label(&label("type::bug", "#d73a4a", None))
+        .await
+        .unwrap();
+    assert!(provider
+        .create_label(&label("Type::Bug", "#000000", None))
+        .await
+        .is_err());
+    provider
+        .update_label(&label("type::bug", "#ff0000", Some("Bug")))
+        .await
+        .unwrap();
+
+    let labels = provider.list_labels().await.unwrap();
+    assert_eq!(labels.len(), 1);
+    assert_eq!(labels[0].color, "#ff0000");
+
+    provider
+        .create_issue(&IssueCreate {
+            title: "Crash: \"quotes\" & colons".to_string(),
+            description: "## Objectif\nFix it".to_string(),
+            labels: vec!["type::bug".to_string()],
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    let issue = provider.get_issue(1).await.unwrap();
+    assert_eq!(issue.title, "Crash: \"quotes\" & colons");
+    assert_eq!(issue.description, "## Objectif\nFix it");
+    assert!(issue.web_url.ends_with("0001-crash-quotes-colons.md"));
+
+    let filter = IssueFilter {
+        labels: vec!["TYPE::BUG".to_string()],
+        ..IssueFilter::default()
+    };
+    assert_eq!(provider.list_issues(&filter).await.unwrap().len(), 1);
+
+    let filter = IssueFilter {
+        milestone: Some("MVP".to_string()),
+        ..IssueFilter::default()
+    };
+    assert!(provider.list_issues(&filter).await.unwrap().is_empty());
+
+    provider.delete_label("type::bug").await.unwrap();
+    assert!(provider.list_labels().await.unwrap().is_empty());
+    assert!(matches!(
+        provider.delete_label("type::bug").await,
+        Err(ProviderError::NotFound(_))
+    ));
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[tokio::test]
+async fn test_local_typed_links_in_front_matter() {
+    let root = temp_root("links");
+    let provider = LocalProvider::new(&root);
+
+    for title in ["Epic", "Story", "Docs"] {
+        provider
+            .create_issue(&IssueCreate {
+                title: title.to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+    }
+
+    provider
+        .create_issue_link(1, 2, LinkKind::Parent)
+        .await
+        .unwrap();
+    provider
+        .create_issue_link(1, 3, LinkKind::RelatesTo)
+        .await
+        .unwrap();
+    provider
+        .create_issue_link(3, 1, LinkKind::Blocks)
+        .await
+        .unwrap();
+    // Déjà présent : le fichier n'est pas modifié
+    provider
+        .create_issue_link(2, 1, LinkKind::Child)
+        .await
+        .unwrap();
+
+    let epic = fs::read_to_string(root.join("issues/0001-epic.md")).unwrap();
+    assert!(epic.contains("depends_on:\n  - 3\nrelated_to:\n  - 3\n---\n"));
+
+    let story = fs::read_to_string(root.join("issues/0002-story.md")).unwrap();
+    assert!(story.contains("state: open\nparent: 1\nlabels:\n"));
+
+    assert!(matches!(
+        provider.create_issue_link(1, 4, LinkKind::RelatesTo).await,
+        Err(ProviderError::NotFound(_))
+    ));
+    assert!(matches!(
+        provider.get_issue(4).await,
+        Err(ProviderError::NotFound(_))
+    ));
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[tokio::test]
+async fn test_local_issue_keeps_assignees_due_date_and_weight() {
+    let root = temp_root("fields");
+    let provider = LocalProvider::new(&root);
+
+    for title in ["Fix login", "Docs"] {
+        provider
+            .create_issue(&IssueCreate {
+                title: title.to_string(),
+                milestone: Some("MVP".to_string()),
+                assignees: vec!["jdoe".to_string(), "hubot".to_string()],
+                due_date: Some("2024-12-31".to_string()),
+                weight: Some(3),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+    }
+
+    // Le fichier relu puis réécrit conserve les champs
+    provider
+        .create_issue_link(1, 2, LinkKind::BlockedBy)
+        .await
+        .unwrap();
+
+    let content = fs::read_to_string(root.join("issues/0001-fix-login.md")).unwrap();
+    assert!(content.starts_with(
+        "---\nid: 1\ntitle: \"Fix login\"\nstate: open\nmilestone: \"MVP\"\ndue_date: \"2024-12-31\"\nweight: 3\nlabels:\ndepends_on:\n  - 2\nassignees:\n  - \"jdoe\"\n  - \"hubot\"\n---\n"
+    ));
+
+    fs::remove_dir_all(&root).unwrap();
+}
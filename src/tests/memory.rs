@@ -0,0 +1,186 @@
+use super::{label, project_issue, sample_project};
+use crate::engine::setup_project;
+use crate::error::ProviderError;
+use crate::models::common::LinkKind;
+use crate::providers::{InMemoryProvider, Operation};
+use crate::state::ProjectState;
+use crate::sync::sync_labels;
+use crate::traits::repository::RepositoryProvider;
+
+#[tokio::test]
+async fn test_memory_setup_project_operation_order() {
+    let provider = InMemoryProvider::new();
+
+    let mut state = ProjectState::default();
+    setup_project(&provider, &sample_project(), &mut state)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        provider.operations(),
+        [
+            Operation::ListMilestones,
+            Operation::ListIssues,
+            Operation::CreateMilestone("MVP".to_string()),
+            Operation::CreateIssue("Setup CI".to_string()),
+            Operation::CreateIssue("Write API".to_string()),
+            Operation::CreateIssueLink {
+                from: 2,
+                to: 1,
+                kind: LinkKind::BlockedBy,
+            },
+        ]
+    );
+    assert_eq!(state.milestone_id("0.1.0"), Some(1));
+    assert_eq!(state.issue_id("Write API"), Some(2));
+
+    let issues = provider.issues();
+    assert_eq!(issues[1].milestone.as_deref(), Some("MVP"));
+    assert!(issues[1]
+        .description
+        .contains("<!-- repo_manager:issue Write API -->"));
+    assert_eq!(provider.links(), [(2, 1, LinkKind::BlockedBy)]);
+
+    // Nouvel état : tout est retrouvé, seules les relectures sont journalisées
+    provider.clear_operations();
+    let mut state = ProjectState::default();
+    setup_project(&provider, &sample_project(), &mut state)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        provider.operations(),
+        [
+            Operation::ListMilestones,
+            Operation::ListIssues,
+            Operation::CreateIssueLink {
+                from: 2,
+                to: 1,
+                kind: LinkKind::BlockedBy,
+            },
+        ]
+    );
+    assert_eq!(provider.issues().len(), 2);
+}
+
+#[tokio::test]
+async fn test_memory_setup_project_creates_dependencies_first() {
+    let provider = InMemoryProvider::new();
+    let mut project = sample_project();
+    project.issues.reverse();
+    project.issues[0].sprint = 2;
+    project
+        .issues
+        .insert(0, project_issue("Docs", "0.1.0", &["Write API"]));
+
+    let mut state = ProjectState::default();
+    let report = setup_project(&provider, &project, &mut state)
+        .await
+        .unwrap();
+
+    let created: Vec<Operation> = provider
+        .operations()
+        .into_iter()
+        .filter(|operation| matches!(operation, Operation::CreateIssue(_)))
+        .collect();
+    assert_eq!(
+        created,
+        [
+            Operation::CreateIssue("Setup CI".to_string()),
+            Operation::CreateIssue("Write API".to_string()),
+            Operation::CreateIssue("Docs".to_string()),
+        ]
+    );
+
+    // « Docs » (sprint 1) attend « Write API » (sprint 2)
+    assert_eq!(report.schedule_conflicts.len(), 1);
+    assert_eq!(report.schedule_conflicts[0].issue, "Docs");
+    assert_eq!(report.schedule_conflicts[0].dependency_sprint, 2);
+}
+
+#[tokio::test]
+async fn test_memory_setup_project_resumes_after_failure() {
+    // Le cinquième appel, création de « Write API », échoue
+    let provider = InMemoryProvider::new().fail_on_call(5);
+
+    let mut state = ProjectState::default();
+    state.begin(false).unwrap();
+    let error = setup_project(&provider, &sample_project(), &mut state)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, ProviderError::Network(_)));
+    assert!(state.is_interrupted());
+    assert_eq!(state.issue_id("Setup CI"), Some(1));
+    assert_eq!(state.issue_id("Write API"), None);
+    assert_eq!(provider.issues().len(), 1);
+
+    provider.clear_operations();
+    state.begin(true).unwrap();
+    setup_project(&provider, &sample_project(), &mut state)
+        .await
+        .unwrap();
+    state.complete().unwrap();
+
+    // La reprise ne relit pas le repository et ne recrée que ce qui manque
+    assert_eq!(
+        provider.operations(),
+        [
+            Operation::CreateIssue("Write API".to_string()),
+            Operation::CreateIssueLink {
+                from: 2,
+                to: 1,
+                kind: LinkKind::BlockedBy,
+            },
+        ]
+    );
+    assert_eq!(provider.issues().len(), 2);
+    assert!(state.has_link("Write API", "Setup CI", LinkKind::BlockedBy));
+}
+
+#[tokio::test]
+async fn test_memory_label_sync_continues_after_failure() {
+    let provider = InMemoryProvider::new()
+        .with_labels(vec![
+            label("type::feature", "#428BCA", Some("Old description")),
+            label("wontfix", "#FFFFFF", None),
+        ])
+        // Appel 1 : liste des labels, appel 2 : création de « type::bug »
+        .fail_on_call(2);
+
+    let desired = [
+        label("type::bug", "#D9534F", None),
+        label("type::feature", "#428BCA", Some("New feature")),
+    ];
+    let report = sync_labels(&provider, &desired, true).await.unwrap();
+
+    assert_eq!(
+        provider.operations(),
+        [
+            Operation::ListLabels,
+            Operation::CreateLabel("type::bug".to_string()),
+            Operation::UpdateLabel("type::feature".to_string()),
+            Operation::DeleteLabel("wontfix".to_string()),
+        ]
+    );
+    assert_eq!(report.failed.len(), 1);
+    assert_eq!(report.failed[0].0, "type::bug");
+    assert_eq!(report.updated, ["type::feature"]);
+    assert_eq!(report.deleted, ["wontfix"]);
+
+    let labels = provider.labels();
+    assert_eq!(labels.len(), 1);
+    assert_eq!(labels[0].description.as_deref(), Some("New feature"));
+
+    // Les erreurs reproduisent celles d'une forge
+    assert!(matches!(
+        provider.delete_label("wontfix").await,
+        Err(ProviderError::NotFound(_))
+    ));
+    assert!(matches!(
+        provider
+            .create_label(&label("TYPE::FEATURE", "#000000", None))
+            .await,
+        Err(ProviderError::Validation(_))
+    ));
+}
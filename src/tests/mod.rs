@@ -1,79 +1,1004 @@
-#[cfg(test)]
-mod tests {
-    use crate::models::common::{
-        IssueDescription, Label, Milestone, Project, ProjectIssue, Section,
-    };
-    use crate::providers::{create_provider, ProviderConfig, ProviderType};
-    use std::env;
-
-    // Helper pour créer une configuration de test
-    fn create_test_config() -> ProviderConfig {
-        ProviderConfig {
-            api_url: "https://api.github.com".to_string(),
-            token: "test_token".to_string(),
-            repository: "test/repo".to_string(),
-        }
+mod azure;
+mod bitbucket;
+mod gitea;
+mod github;
+mod gitlab;
+mod jira;
+mod local;
+mod memory;
+
+use crate::engine::{setup_project, ProjectEngine, SetupEvent};
+use crate::error::ProviderError;
+use crate::models::common::{
+    Estimate, IssueCreate, IssueDescription, IssueFilter, IssueLink, IssueState, Label, LinkKind,
+    Milestone, Project, ProjectIssue, RemoteIssue, Section,
+};
+use crate::plan::{plan_project, Action, Plan};
+use crate::providers::existing::ExistingEntities;
+use crate::providers::http::{is_rate_limited, rate_limit_delay, HttpClient};
+use crate::providers::pagination::{next_link, next_page};
+use crate::providers::{
+    create_provider, InMemoryProvider, ProviderConfig, ProviderType, RetryPolicy,
+};
+use crate::schedule::{ScheduleOptions, ScheduleReport};
+use crate::state::{ProjectState, StateFile};
+use crate::sync::{diff_labels, LabelCapabilities, LabelChange};
+use crate::traits::repository::RepositoryProvider;
+use crate::validation::{
+    schedule_conflicts, validate_project, DependencyGraph, ProjectError, ScheduleConflict,
+};
+use std::env;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+// Helper pour créer une configuration de test
+fn create_test_config() -> ProviderConfig {
+    ProviderConfig {
+        api_url: "https://api.github.com".to_string(),
+        token: "test_token".to_string(),
+        repository: "test/repo".to_string(),
+        retry: RetryPolicy::default(),
     }
+}
+
+#[test]
+fn test_create_provider() {
+    let config = create_test_config();
+
+    let github_provider = create_provider(ProviderType::GitHub, config.clone());
+    assert!(github_provider.is_ok(), "Should create GitHub provider");
+
+    let gitlab_provider = create_provider(ProviderType::GitLab, config);
+    assert!(gitlab_provider.is_ok(), "Should create GitLab provider");
+}
+
+#[test]
+fn test_provider_config_builder() {
+    let config = ProviderConfig::builder()
+        .api_url("https://gitlab.com/api/v4/")
+        .token("test_token")
+        .repository("group/project")
+        .build()
+        .expect("Should build config");
+
+    assert_eq!(config.api_url, "https://gitlab.com/api/v4");
+    assert_eq!(config.repository, "group/project");
+
+    let missing_token = ProviderConfig::builder()
+        .api_url("https://gitlab.com/api/v4")
+        .repository("group/project")
+        .build();
+    assert!(matches!(missing_token, Err(ProviderError::Config(_))));
+}
+
+#[test]
+fn test_label_creation() {
+    let label = Label {
+        name: "test".to_string(),
+        color: "#ff0000".to_string(),
+        description: Some("Test label".to_string()),
+    };
+
+    assert_eq!(label.name, "test");
+    assert_eq!(label.color, "#ff0000");
+    assert_eq!(label.description, Some("Test label".to_string()));
+}
+
+#[test]
+fn test_issue_description_to_markdown() {
+    let description = IssueDescription {
+        sections: vec![Section {
+            title: "## Test".to_string(),
+            content: vec!["Line 1".to_string(), "Line 2".to_string()],
+        }],
+    };
+
+    let markdown = description.to_markdown();
+    assert!(markdown.contains("## Test"));
+    assert!(markdown.contains("Line 1"));
+    assert!(markdown.contains("Line 2"));
+}
+
+#[test]
+fn test_estimate_parsing() {
+    assert_eq!(Estimate::parse("1d"), Some(Estimate::Hours(8.0)));
+    assert_eq!(Estimate::parse(" 4H "), Some(Estimate::Hours(4.0)));
+    assert_eq!(Estimate::parse("0,5d"), Some(Estimate::Hours(4.0)));
+    assert_eq!(Estimate::parse("1w"), Some(Estimate::Hours(40.0)));
+    assert_eq!(Estimate::parse("3"), Some(Estimate::Points(3.0)));
+    assert_eq!(Estimate::parse("5 sp"), Some(Estimate::Points(5.0)));
+    assert_eq!(Estimate::parse(""), None);
+    assert_eq!(Estimate::parse("2 months"), None);
+}
 
-    #[test]
-    fn test_create_provider() {
-        let config = create_test_config();
+#[test]
+fn test_project_issue_links() {
+    let issue: ProjectIssue = serde_json::from_value(serde_json::json!({
+        "title": "Write API",
+        "milestone": "0.1.0",
+        "estimate": "1d",
+        "sprint": 1,
+        "dependencies": ["Setup CI"],
+        "links": [
+            { "title": "Epic", "kind": "child" },
+            { "title": "Docs", "kind": "relates-to" },
+            { "title": "Deploy" }
+        ],
+        "labels": [],
+        "description": { "sections": [] }
+    }))
+    .unwrap();
 
-        let github_provider = create_provider(ProviderType::GitHub, config.clone());
-        assert!(github_provider.is_ok(), "Should create GitHub provider");
+    assert_eq!(
+        issue.relations().collect::<Vec<_>>(),
+        [
+            ("Setup CI", LinkKind::BlockedBy),
+            ("Epic", LinkKind::Child),
+            ("Docs", LinkKind::RelatesTo),
+            ("Deploy", LinkKind::BlockedBy),
+        ]
+    );
+    assert_eq!(LinkKind::Blocks.describe(3), "Blocks #3");
 
-        let gitlab_provider = create_provider(ProviderType::GitLab, config);
-        assert!(gitlab_provider.is_ok(), "Should create GitLab provider");
+    // Les liens enregistrés avant l'ajout des types sont des dépendances
+    let state: ProjectState = serde_json::from_value(serde_json::json!({
+        "links": [{ "from": "Write API", "to": "Setup CI" }]
+    }))
+    .unwrap();
+    assert!(state.has_link("Write API", "Setup CI", LinkKind::BlockedBy));
+    assert!(!state.has_link("Write API", "Setup CI", LinkKind::RelatesTo));
+}
+
+#[tokio::test]
+async fn test_issue_state_and_filter() {
+    let state: IssueState = serde_json::from_str("\"closed\"").unwrap();
+    assert_eq!(state, IssueState::Closed);
+
+    let provider = InMemoryProvider::new();
+    for (title, labels, milestone) in [
+        ("Crash", vec!["type::bug"], Some("MVP")),
+        ("Login", vec!["type::feature", "priority::1"], Some("MVP")),
+        ("Docs", vec!["type::feature"], None),
+    ] {
+        provider
+            .create_issue(&IssueCreate {
+                title: title.to_string(),
+                labels: labels.into_iter().map(str::to_string).collect(),
+                milestone: milestone.map(str::to_string),
+                ..IssueCreate::default()
+            })
+            .await
+            .unwrap();
     }
 
-    #[test]
-    fn test_label_creation() {
-        let label = Label {
-            name: "test".to_string(),
-            color: "#ff0000".to_string(),
-            description: Some("Test label".to_string()),
-        };
+    let titles = |issues: Vec<RemoteIssue>| -> Vec<String> {
+        issues.into_iter().map(|issue| issue.title).collect()
+    };
+
+    let all = provider.list_issues(&IssueFilter::default()).await.unwrap();
+    assert_eq!(titles(all), ["Crash", "Login", "Docs"]);
 
-        assert_eq!(label.name, "test");
-        assert_eq!(label.color, "#ff0000");
-        assert_eq!(label.description, Some("Test label".to_string()));
+    // Les labels doivent tous être présents, sans tenir compte de la casse
+    let filter = IssueFilter {
+        labels: vec!["TYPE::FEATURE".to_string(), "priority::1".to_string()],
+        ..IssueFilter::default()
+    };
+    assert_eq!(
+        titles(provider.list_issues(&filter).await.unwrap()),
+        ["Login"]
+    );
+
+    let filter = IssueFilter {
+        milestone: Some("MVP".to_string()),
+        labels: vec!["type::feature".to_string()],
+        ..IssueFilter::default()
+    };
+    assert_eq!(
+        titles(provider.list_issues(&filter).await.unwrap()),
+        ["Login"]
+    );
+
+    let filter = IssueFilter {
+        state: Some(IssueState::Closed),
+        ..IssueFilter::default()
+    };
+    assert!(provider.list_issues(&filter).await.unwrap().is_empty());
+}
+
+fn project_issue(title: &str, milestone: &str, dependencies: &[&str]) -> ProjectIssue {
+    ProjectIssue {
+        title: title.to_string(),
+        milestone: milestone.to_string(),
+        estimate: "1d".to_string(),
+        sprint: 1,
+        dependencies: dependencies.iter().map(|d| d.to_string()).collect(),
+        links: vec![],
+        labels: vec![],
+        description: IssueDescription { sections: vec![] },
     }
+}
 
-    #[test]
-    fn test_issue_description_to_markdown() {
-        let description = IssueDescription {
-            sections: vec![Section {
-                title: "## Test".to_string(),
-                content: vec!["Line 1".to_string(), "Line 2".to_string()],
-            }],
-        };
+fn sample_project() -> Project {
+    Project {
+        name: "Test Project".to_string(),
+        version: "0.1.0".to_string(),
+        milestones: vec![Milestone {
+            name: "MVP".to_string(),
+            version: "0.1.0".to_string(),
+            deadline: "2024-12-31".to_string(),
+            description: "First release".to_string(),
+        }],
+        issues: vec![
+            project_issue("Setup CI", "0.1.0", &[]),
+            project_issue("Write API", "0.1.0", &["Setup CI"]),
+        ],
+    }
+}
 
-        let markdown = description.to_markdown();
-        assert!(markdown.contains("## Test"));
-        assert!(markdown.contains("Line 1"));
-        assert!(markdown.contains("Line 2"));
+fn label(name: &str, color: &str, description: Option<&str>) -> Label {
+    Label {
+        name: name.to_string(),
+        color: color.to_string(),
+        description: description.map(|d| d.to_string()),
     }
+}
 
-    #[tokio::test]
-    #[ignore] // Ignorer par défaut car nécessite des tokens valides
-    async fn test_github_integration() {
-        let token = env::var("GITHUB_TOKEN").expect("GITHUB_TOKEN must be set");
-        let config = ProviderConfig {
-            api_url: "https://api.github.com".to_string(),
-            token,
-            repository: env::var("GITHUB_REPO").expect("GITHUB_REPO must be set"),
-        };
+/// Configuration d'un provider pointé sur un serveur wiremock, sans nouvelle tentative
+fn mock_config(server: &MockServer, repository: &str) -> ProviderConfig {
+    ProviderConfig::builder()
+        .api_url(server.uri())
+        .token("test_token")
+        .repository(repository)
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .unwrap()
+}
+
+/// Réponse enregistrée d'une forge, lue dans `src/tests/fixtures`
+fn fixture(name: &str) -> serde_json::Value {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src/tests/fixtures")
+        .join(name);
+    let content = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Missing fixture {}: {}", path.display(), e));
 
-        let provider = create_provider(ProviderType::GitHub, config).unwrap();
+    serde_json::from_str(&content).unwrap()
+}
+
+#[test]
+fn test_diff_labels() {
+    let desired = vec![
+        label("priority::1", "#FF0000", Some("Highest priority")),
+        label("type::feature", "#428BCA", Some("New feature")),
+        label("type::bug", "#D9534F", None),
+    ];
+    let existing = vec![
+        // Même label, couleur sans # et en minuscules : inchangé
+        label("Priority::1", "ff0000", Some("Highest priority")),
+        label("type::feature", "#428BCA", Some("Old description")),
+        label("wontfix", "#FFFFFF", None),
+    ];
+
+    let changes = diff_labels(&desired, &existing, false, LabelCapabilities::default());
+    assert_eq!(changes.len(), 3);
+    assert!(matches!(&changes[0], LabelChange::Unchanged(l) if l.name == "Priority::1"));
+    assert!(matches!(
+        &changes[1],
+        LabelChange::Update { desired, .. } if desired.description.as_deref() == Some("New feature")
+    ));
+    assert!(matches!(&changes[2], LabelChange::Create(l) if l.name == "type::bug"));
+
+    let changes = diff_labels(&desired, &existing, true, LabelCapabilities::default());
+    assert_eq!(changes.len(), 4);
+    assert!(matches!(&changes[3], LabelChange::Delete(l) if l.name == "wontfix"));
+
+    // Sans couleurs côté provider, seuls les noms comptent
+    let capabilities = LabelCapabilities {
+        colors: false,
+        ..LabelCapabilities::default()
+    };
+    let changes = diff_labels(&desired, &existing, false, capabilities);
+    assert!(matches!(&changes[1], LabelChange::Unchanged(l) if l.name == "type::feature"));
+
+    // Sans suppression côté provider, `prune` est ignoré
+    let capabilities = LabelCapabilities {
+        delete: false,
+        ..LabelCapabilities::default()
+    };
+    let changes = diff_labels(&desired, &existing, true, capabilities);
+    assert_eq!(changes.len(), 3);
+}
+
+#[test]
+fn test_project_plan() {
+    let project = sample_project();
+    let existing = ExistingEntities {
+        milestones: vec![],
+        issues: vec![RemoteIssue {
+            id: 7,
+            title: "Setup CI".to_string(),
+            description: String::new(),
+            state: IssueState::Open,
+            labels: vec![],
+            milestone: Some("MVP".to_string()),
+            web_url: "https://example.com/7".to_string(),
+        }],
+    };
 
-        // Test label creation
-        let test_label = Label {
-            name: "test_label".to_string(),
-            color: "#ff0000".to_string(),
-            description: Some("Test label".to_string()),
+    let plan = Plan::for_project(&project, &existing, &ProjectState::default());
+    assert_eq!(plan.count(Action::Create), 3);
+    assert_eq!(plan.count(Action::Keep), 1);
+
+    let rendered = plan.to_string();
+    assert!(rendered.contains("+ milestone \"MVP\" (due 2024-12-31)"));
+    assert!(rendered.contains("= issue \"Setup CI\" (already exists #7)"));
+    assert!(rendered.contains("+ issue \"Write API\" in milestone \"MVP\""));
+    assert!(rendered.contains("+ link \"Write API\" -> \"Setup CI\""));
+    assert!(rendered.ends_with("Plan: 3 to create, 0 to update, 0 to delete, 1 unchanged."));
+}
+
+#[tokio::test]
+async fn test_project_plan_after_setup_has_no_changes() {
+    let provider = InMemoryProvider::new();
+    let project = sample_project();
+
+    let mut state = ProjectState::default();
+    setup_project(&provider, &project, &mut state)
+        .await
+        .unwrap();
+
+    let plan = plan_project(&provider, &project, &state).await.unwrap();
+    assert!(!plan.has_changes());
+    assert!(plan
+        .to_string()
+        .contains("= link \"Write API\" -> \"Setup CI\" (already exists)"));
+
+    // Sans état, les liens restent à créer
+    let plan = plan_project(&provider, &project, &ProjectState::default())
+        .await
+        .unwrap();
+    assert_eq!(plan.count(Action::Create), 1);
+}
+
+#[tokio::test]
+async fn test_engine_reports_progress() {
+    let provider = InMemoryProvider::new();
+    let events = Mutex::new(Vec::new());
+
+    let engine = ProjectEngine::new(&provider).on_event(|event| {
+        let description = match event {
+            SetupEvent::MilestoneCreated { milestone, remote } => {
+                format!("+ milestone {} {}", milestone.name, remote.id)
+            }
+            SetupEvent::MilestoneReused { milestone, id } => {
+                format!("= milestone {} {}", milestone.name, id)
+            }
+            SetupEvent::IssueCreated { issue, remote } => format!(
+                "+ issue {} {} {}",
+                issue.title,
+                remote.id,
+                remote.web_url.as_deref().unwrap_or_default()
+            ),
+            SetupEvent::IssueReused { issue, id } => format!("= issue {} {}", issue.title, id),
+            SetupEvent::LinkCreated { from, to, .. } => format!("+ link {} -> {}", from.title, to),
         };
+        events.lock().unwrap().push(description);
+    });
+
+    let mut state = ProjectState::default();
+    let report = engine.setup(&sample_project(), &mut state).await.unwrap();
+
+    assert_eq!(
+        report.summary(),
+        "1 milestones created (0 reused), 2 issues created (0 reused), 1 links created"
+    );
+
+    // Seconde exécution avec le même état : rien n'est recréé
+    let report = engine.setup(&sample_project(), &mut state).await.unwrap();
+    assert_eq!(report.milestones_reused, 1);
+    assert_eq!(report.issues_reused, 2);
+    assert_eq!(report.links_created, 0);
+
+    drop(engine);
+    assert_eq!(
+        events.into_inner().unwrap(),
+        [
+            "+ milestone MVP 1",
+            "+ issue Setup CI 1 memory://issues/1",
+            "+ issue Write API 2 memory://issues/2",
+            "+ link Write API -> Setup CI",
+            "= milestone MVP 1",
+            "= issue Setup CI 1",
+            "= issue Write API 2",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_engine_rejects_unknown_milestone_before_any_call() {
+    let provider = InMemoryProvider::new();
+    let mut project = sample_project();
+    project
+        .issues
+        .push(project_issue("Deploy", "9.9.9", &["Write API"]));
+
+    let mut state = ProjectState::default();
+    let error = setup_project(&provider, &project, &mut state)
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(error, ProviderError::InvalidProject(errors) if errors.to_string().contains("9.9.9"))
+    );
+    assert!(provider.operations().is_empty());
+}
+
+#[test]
+fn test_validate_project() {
+    assert_eq!(validate_project(&sample_project()), Ok(()));
+
+    let mut project = sample_project();
+    project.issues.extend([
+        project_issue("Setup CI", "0.1.0", &[]),
+        project_issue("Deploy", "9.9.9", &["Deploy", "Monitoring"]),
+        // Write API -> Review -> Release -> Write API
+        project_issue("Review", "0.1.0", &["Release"]),
+        project_issue("Release", "0.1.0", &[]),
+    ]);
+    project.issues[1].dependencies.push("Review".to_string());
+    project.issues[1].links.push(IssueLink {
+        title: "Release".to_string(),
+        kind: LinkKind::Blocks,
+    });
+    // Les liens sans contrainte d'ordre ne forment pas de cycle
+    project.issues[0].links.push(IssueLink {
+        title: "Write API".to_string(),
+        kind: LinkKind::RelatesTo,
+    });
+
+    let errors = validate_project(&project).unwrap_err();
+    assert_eq!(
+        errors.0,
+        [
+            ProjectError::DuplicateIssue("Setup CI".to_string()),
+            ProjectError::UnknownMilestone {
+                issue: "Deploy".to_string(),
+                milestone: "9.9.9".to_string(),
+            },
+            ProjectError::SelfLink {
+                issue: "Deploy".to_string(),
+                kind: LinkKind::BlockedBy,
+            },
+            ProjectError::UnknownIssue {
+                issue: "Deploy".to_string(),
+                target: "Monitoring".to_string(),
+                kind: LinkKind::BlockedBy,
+            },
+            ProjectError::Cycle(vec![
+                "Write API".to_string(),
+                "Review".to_string(),
+                "Release".to_string(),
+                "Write API".to_string(),
+            ]),
+        ]
+    );
+    assert!(errors.to_string().ends_with(
+        "Dependency cycle: \"Write API\" -> \"Review\" -> \"Release\" -> \"Write API\""
+    ));
+}
+
+#[test]
+fn test_dependency_order_and_schedule_conflicts() {
+    let mut project = sample_project();
+    project.milestones.push(Milestone {
+        name: "Beta".to_string(),
+        version: "0.2.0".to_string(),
+        deadline: "2025-03-31".to_string(),
+        description: "Second release".to_string(),
+    });
+    // Déclarées avant les issues qu'elles attendent
+    project
+        .issues
+        .insert(0, project_issue("Docs", "0.1.0", &["Write API", "Metrics"]));
+    project.issues.push(project_issue("Metrics", "0.2.0", &[]));
+    project.issues[2].sprint = 2;
+    project.issues[2].links.push(IssueLink {
+        title: "Metrics".to_string(),
+        kind: LinkKind::Blocks,
+    });
+
+    // Docs, Setup CI, Write API, Metrics
+    let order = DependencyGraph::new(&project).topological_order();
+    assert_eq!(order, [1, 2, 3, 0]);
+
+    assert_eq!(
+        schedule_conflicts(&project),
+        [
+            ScheduleConflict {
+                issue: "Docs".to_string(),
+                milestone: "0.1.0".to_string(),
+                sprint: 1,
+                dependency: "Write API".to_string(),
+                dependency_milestone: "0.1.0".to_string(),
+                dependency_sprint: 2,
+            },
+            ScheduleConflict {
+                issue: "Docs".to_string(),
+                milestone: "0.1.0".to_string(),
+                sprint: 1,
+                dependency: "Metrics".to_string(),
+                dependency_milestone: "0.2.0".to_string(),
+                dependency_sprint: 1,
+            },
+            // Milestone à l'échéance plus proche, mais sprint suivant
+            ScheduleConflict {
+                issue: "Metrics".to_string(),
+                milestone: "0.2.0".to_string(),
+                sprint: 1,
+                dependency: "Write API".to_string(),
+                dependency_milestone: "0.1.0".to_string(),
+                dependency_sprint: 2,
+            },
+        ]
+    );
+    assert_eq!(
+        schedule_conflicts(&project)[1].to_string(),
+        "\"Docs\" (milestone 0.1.0, sprint 1) depends on \"Metrics\" (milestone 0.2.0, sprint 1)"
+    );
+    assert!(schedule_conflicts(&sample_project()).is_empty());
+}
+
+#[test]
+fn test_schedule_report() {
+    let mut project = sample_project();
+    project.milestones[0].deadline = "2024-01-09".to_string();
+    project.milestones.push(Milestone {
+        name: "Beta".to_string(),
+        version: "0.2.0".to_string(),
+        deadline: "2024-01-31".to_string(),
+        description: "Second release".to_string(),
+    });
+    project.issues[0].estimate = "3d".to_string();
+    project
+        .issues
+        .push(project_issue("Docs", "0.2.0", &["Write API"]));
+    project.issues.push(project_issue("Logo", "0.2.0", &[]));
+    project.issues[2].estimate = "2".to_string();
+    project.issues[2].sprint = 2;
+    project.issues[3].estimate = "soon".to_string();
+
+    // Le 5 janvier 2024 est un vendredi
+    let options = ScheduleOptions {
+        start: "2024-01-05".to_string(),
+        hours_per_point: 4.0,
+    };
+    let report = ScheduleReport::for_project(&project, &options).unwrap();
+
+    assert_eq!(report.critical_path, ["Setup CI", "Write API", "Docs"]);
+    assert_eq!(report.critical_path_hours, 40.0);
+    assert_eq!(report.issues[1].earliest_start, 24.0);
+    assert!(!report.issues[3].critical);
+    assert_eq!(report.unestimated, ["Logo"]);
+
+    assert_eq!(report.sprints.len(), 2);
+    assert_eq!(
+        (report.sprints[0].issues, report.sprints[0].hours),
+        (3, 32.0)
+    );
+    assert_eq!(
+        (report.sprints[1].issues, report.sprints[1].hours),
+        (1, 8.0)
+    );
 
-        let result = provider.create_label(&test_label).await;
-        assert!(result.is_ok(), "Should create label successfully");
+    // 4 jours ouvrés : vendredi, puis lundi à mercredi
+    let mvp = &report.milestones[0];
+    assert_eq!((mvp.issues, mvp.hours), (2, 32.0));
+    assert_eq!(mvp.projected, "2024-01-10");
+    assert_eq!(mvp.feasible, Some(false));
+
+    let beta = &report.milestones[1];
+    assert_eq!(beta.projected, "2024-01-11");
+    assert_eq!(beta.feasible, Some(true));
+
+    let rendered = report.to_string();
+    assert!(rendered.contains("Critical path (40h): Setup CI -> Write API -> Docs"));
+    assert!(rendered.contains("MVP (0.1.0)               2      32h 2024-01-09  2024-01-10  LATE"));
+
+    let json = serde_json::to_value(&report).unwrap();
+    assert_eq!(json["milestones"][1]["feasible"], true);
+
+    let options = ScheduleOptions {
+        start: "2024-02-30".to_string(),
+        ..ScheduleOptions::default()
+    };
+    assert!(matches!(
+        ScheduleReport::for_project(&project, &options),
+        Err(ProviderError::Config(_))
+    ));
+}
+
+#[test]
+fn test_schedule_report_with_huge_estimate() {
+    let mut project = sample_project();
+    project.issues[0].estimate = "99999999999999w".to_string();
+    project.issues[1].estimate = "10000000000".to_string();
+
+    // Le 6 janvier 2024 est un samedi : le travail commence le lundi
+    let options = ScheduleOptions {
+        start: "2024-01-06".to_string(),
+        hours_per_point: 4.0,
+    };
+    let report = ScheduleReport::for_project(&project, &options).unwrap();
+
+    let mvp = &report.milestones[0];
+    assert_eq!(mvp.feasible, Some(false));
+    // Projection plafonnée à environ 10 000 ans
+    let year: i64 = mvp.projected.split('-').next().unwrap().parse().unwrap();
+    assert!(year > 9999);
+
+    // Une journée de travail commencée un samedi se termine le lundi
+    project.issues[0].estimate = "1d".to_string();
+    project.issues[1].estimate = "0".to_string();
+    let report = ScheduleReport::for_project(&project, &options).unwrap();
+    assert_eq!(report.milestones[0].projected, "2024-01-08");
+}
+
+#[test]
+fn test_existing_issue_matching() {
+    let issue = project_issue("Write API", "0.1.0", &[]);
+    let remote = |id: i64, title: &str, description: String| RemoteIssue {
+        id,
+        title: title.to_string(),
+        description,
+        state: IssueState::Open,
+        labels: vec![],
+        milestone: None,
+        web_url: String::new(),
+    };
+
+    // Le marqueur prime sur le titre, même si l'issue a été renommée
+    let existing = ExistingEntities {
+        milestones: vec![],
+        issues: vec![
+            remote(1, "Write API", String::new()),
+            remote(2, "Write the REST API", issue.to_markdown()),
+        ],
+    };
+    assert_eq!(existing.issue(&issue).map(|i| i.id), Some(2));
+
+    let existing = ExistingEntities {
+        milestones: vec![],
+        issues: vec![remote(1, "Write API", String::new())],
+    };
+    assert_eq!(existing.issue(&issue).map(|i| i.id), Some(1));
+
+    let missing = project_issue("Deploy", "0.1.0", &[]);
+    assert!(existing.issue(&missing).is_none());
+}
+
+#[test]
+fn test_state_file_roundtrip() {
+    let dir = env::temp_dir().join(format!("repo_manager_state_{}", std::process::id()));
+    let path = dir.join("state.json");
+
+    let mut state_file = StateFile::load(&path).expect("Missing state file is empty");
+    let key = StateFile::key("github", "owner/repo");
+    let state = state_file.project_mut(&key);
+    state.record_milestone("0.1.0", 3).unwrap();
+    state.record_issue("Setup CI", 12).unwrap();
+    state
+        .record_link("Write API", "Setup CI", LinkKind::BlockedBy)
+        .unwrap();
+    state
+        .record_link("Write API", "Setup CI", LinkKind::BlockedBy)
+        .unwrap();
+    state_file.save(&path).expect("Should save state file");
+
+    let loaded = StateFile::load(&path).expect("Should load state file");
+    let state = loaded.project(&key).expect("State for repository");
+    assert_eq!(state.milestone_id("0.1.0"), Some(3));
+    assert_eq!(state.issue_id("Setup CI"), Some(12));
+    assert!(state.has_link("Write API", "Setup CI", LinkKind::BlockedBy));
+    assert_eq!(state.links.len(), 1);
+    assert!(loaded.project("gitlab:owner/repo").is_none());
+
+    std::fs::remove_dir_all(dir).ok();
+}
+
+#[test]
+fn test_state_checkpoint_and_resume() {
+    let dir = env::temp_dir().join(format!("repo_manager_resume_{}", std::process::id()));
+    let path = dir.join("state.json");
+    let key = StateFile::key("gitlab", "group/project");
+
+    let mut state = StateFile::open_project(&path, &key).unwrap();
+    assert!(state.begin(true).is_err(), "Nothing to resume yet");
+
+    state.begin(false).unwrap();
+    state.record_milestone("0.1.0", 1).unwrap();
+    state.record_issue("Setup CI", 2).unwrap();
+    // Interruption : l'état a été sauvegardé à chaque création
+    drop(state);
+
+    let mut state = StateFile::open_project(&path, &key).unwrap();
+    assert!(state.is_interrupted());
+    assert_eq!(state.issue_id("Setup CI"), Some(2));
+
+    state.begin(true).unwrap();
+    assert!(state.is_resuming());
+    state.complete().unwrap();
+
+    let state = StateFile::open_project(&path, &key).unwrap();
+    assert!(!state.is_interrupted());
+    assert!(!state.is_resuming());
+
+    std::fs::remove_dir_all(dir).ok();
+}
+
+#[test]
+fn test_retry_backoff_is_bounded() {
+    let policy = RetryPolicy {
+        max_retries: 10,
+        base_delay: Duration::from_millis(100),
+        max_delay: Duration::from_secs(2),
+        rate_limit_budget: Duration::from_secs(60),
+    };
+
+    for attempt in 0..10 {
+        let expected = (Duration::from_millis(100) * 2u32.pow(attempt)).min(Duration::from_secs(2));
+        let delay = policy.backoff(attempt);
+        assert!(
+            delay >= expected / 2 && delay <= expected,
+            "attempt {}",
+            attempt
+        );
     }
 }
+
+fn retry_client(max_retries: u32, rate_limit_budget: Duration) -> HttpClient {
+    HttpClient::new(
+        reqwest::Client::new(),
+        RetryPolicy {
+            max_retries,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(5),
+            rate_limit_budget,
+        },
+    )
+}
+
+#[tokio::test]
+async fn test_send_retries_transient_errors_on_get() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/issues"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/issues"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = retry_client(3, Duration::from_secs(1));
+    let url = format!("{}/issues", server.uri());
+    let response = client.send(client.get(&url)).await.unwrap();
+    assert_eq!(response.status(), 200);
+}
+
+#[tokio::test]
+async fn test_send_does_not_replay_post() {
+    let server = MockServer::start().await;
+
+    // Le serveur a pu enregistrer l'issue avant de répondre 502
+    Mock::given(method("POST"))
+        .and(path("/issues"))
+        .respond_with(ResponseTemplate::new(502))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = retry_client(3, Duration::from_secs(1));
+    let url = format!("{}/issues", server.uri());
+    let error = client
+        .send(
+            client
+                .post(&url)
+                .json(&serde_json::json!({"title": "Crash"})),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(error.status(), Some(502));
+}
+
+#[tokio::test]
+async fn test_send_waits_for_rate_limit() {
+    let server = MockServer::start().await;
+
+    // `Retry-After: 0` ne doit pas faire boucler sans fin : l'essai compte
+    Mock::given(method("POST"))
+        .and(path("/labels"))
+        .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "0"))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/labels"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = retry_client(3, Duration::from_secs(1));
+    let url = format!("{}/labels", server.uri());
+    let response = client.send(client.post(&url)).await.unwrap();
+    assert_eq!(response.status(), 201);
+}
+
+#[tokio::test]
+async fn test_send_rate_limit_exhaustion() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/slow"))
+        .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "60"))
+        // Un seul appel par client : aucun n'est rejoué
+        .expect(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/busy"))
+        .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "0"))
+        .expect(3)
+        .mount(&server)
+        .await;
+
+    // Attente demandée supérieure au budget
+    let client = retry_client(3, Duration::from_secs(1));
+    let error = client
+        .send(client.get(format!("{}/slow", server.uri())))
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(&error, ProviderError::RateLimit(e) if e.retry_after == Some(Duration::from_secs(60)))
+    );
+
+    // Nombre de retries épuisé
+    let client = retry_client(2, Duration::from_secs(1));
+    let error = client
+        .send(client.get(format!("{}/busy", server.uri())))
+        .await
+        .unwrap_err();
+    assert!(matches!(error, ProviderError::RateLimit(_)));
+
+    // Sans retry, la limite de débit est retournée immédiatement
+    let client = HttpClient::new(reqwest::Client::new(), RetryPolicy::none());
+    let error = client
+        .send(client.get(format!("{}/slow", server.uri())))
+        .await
+        .unwrap_err();
+    assert!(matches!(error, ProviderError::RateLimit(_)));
+}
+
+#[test]
+fn test_rate_limit_detection() {
+    use reqwest::header::{HeaderMap, HeaderValue};
+    use reqwest::StatusCode;
+
+    let mut headers = HeaderMap::new();
+    assert!(is_rate_limited(StatusCode::TOO_MANY_REQUESTS, &headers));
+    // 403 sans en-tête de limite : vraie erreur d'autorisation
+    assert!(!is_rate_limited(StatusCode::FORBIDDEN, &headers));
+    assert_eq!(rate_limit_delay(&headers), None);
+
+    headers.insert("x-ratelimit-remaining", HeaderValue::from_static("0"));
+    assert!(is_rate_limited(StatusCode::FORBIDDEN, &headers));
+
+    headers.insert("retry-after", HeaderValue::from_static("30"));
+    assert_eq!(rate_limit_delay(&headers), Some(Duration::from_secs(30)));
+
+    let reset = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        + 60;
+    let mut headers = HeaderMap::new();
+    headers.insert("ratelimit-reset", HeaderValue::from(reset));
+    let delay = rate_limit_delay(&headers).unwrap();
+    assert!(delay > Duration::from_secs(55) && delay <= Duration::from_secs(61));
+}
+
+#[test]
+fn test_error_from_github_response() {
+    let body = r#"{
+        "message": "Validation Failed",
+        "errors": [{"resource": "Label", "code": "already_exists", "field": "name"}],
+        "documentation_url": "https://docs.github.com/rest/issues/labels#create-a-label"
+    }"#;
+
+    let error =
+        ProviderError::from_response(422, "POST", "https://api.github.com/repos/o/r/labels", body);
+    let ProviderError::Validation(api) = &error else {
+        panic!("Expected validation error, got {:?}", error);
+    };
+    assert_eq!(api.status, 422);
+    assert_eq!(api.method, "POST");
+    assert_eq!(api.message, "Validation Failed");
+    assert_eq!(api.field_errors.len(), 1);
+    assert_eq!(api.field_errors[0].field.as_deref(), Some("name"));
+    assert_eq!(api.field_errors[0].code.as_deref(), Some("already_exists"));
+
+    let error = ProviderError::from_response(401, "GET", "u", r#"{"message": "Bad credentials"}"#);
+    assert!(matches!(error, ProviderError::Auth(_)));
+
+    let error = ProviderError::from_response(404, "GET", "u", "not json");
+    assert!(matches!(&error, ProviderError::NotFound(api) if api.message == "not json"));
+
+    let error = ProviderError::from_response(429, "GET", "u", "");
+    assert!(matches!(error, ProviderError::RateLimit(_)));
+
+    let error = ProviderError::from_response(500, "GET", "u", "");
+    assert_eq!(error.status(), Some(500));
+    assert!(matches!(error, ProviderError::Api(_)));
+}
+
+#[test]
+fn test_error_from_gitlab_response() {
+    let body = r#"{"message": {"name": ["has already been taken"], "color": ["must be a valid color code"]}}"#;
+    let error = ProviderError::from_response(400, "POST", "u", body);
+
+    let ProviderError::Validation(api) = &error else {
+        panic!("Expected validation error, got {:?}", error);
+    };
+    assert_eq!(api.message, "Validation failed");
+    assert_eq!(api.field_errors.len(), 2);
+    assert!(api
+        .field_errors
+        .iter()
+        .any(|e| e.field.as_deref() == Some("name")
+            && e.message.as_deref() == Some("has already been taken")));
+
+    let body = r#"{"error": "invalid_token", "error_description": "Token was revoked"}"#;
+    let error = ProviderError::from_response(401, "GET", "u", body);
+    assert!(matches!(&error, ProviderError::Auth(api) if api.message == "Token was revoked"));
+}
+
+#[test]
+fn test_pagination_next_link() {
+    let link = "<https://api.github.com/repositories/1/issues?page=2>; rel=\"next\", \
+                <https://api.github.com/repositories/1/issues?page=5>; rel=\"last\"";
+    assert_eq!(
+        next_link(link),
+        Some("https://api.github.com/repositories/1/issues?page=2".to_string())
+    );
+
+    let last = "<https://api.github.com/repositories/1/issues?page=4>; rel=\"prev\"";
+    assert_eq!(next_link(last), None);
+}
+
+#[test]
+fn test_pagination_next_page() {
+    use reqwest::header::{HeaderMap, HeaderValue};
+
+    let mut headers = HeaderMap::new();
+    headers.insert("x-next-page", HeaderValue::from_static("3"));
+    assert_eq!(next_page(&headers), Some(3));
+
+    // Dernière page : X-Next-Page vide
+    headers.insert("x-next-page", HeaderValue::from_static(""));
+    headers.insert("x-page", HeaderValue::from_static("4"));
+    headers.insert("x-total-pages", HeaderValue::from_static("4"));
+    assert_eq!(next_page(&headers), None);
+
+    headers.insert("x-page", HeaderValue::from_static("2"));
+    assert_eq!(next_page(&headers), Some(3));
+}
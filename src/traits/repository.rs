@@ -1,23 +1,89 @@
 use crate::error::ProviderError;
-use crate::models::common::{IssueCreate, Label, Milestone, Project};
+use crate::models::common::{
+    IssueCreate, IssueFilter, Label, LinkKind, Milestone, ProjectIssue, RemoteIssue,
+    RemoteMilestone, RemoteRef,
+};
 use async_trait::async_trait;
 
 #[async_trait]
 pub trait RepositoryProvider: Send + Sync {
-    /// Crée un nouveau label dans le repository
-    async fn create_label(&self, label: &Label) -> Result<(), ProviderError>;
+    /// Crée un nouveau label dans le repository et retourne sa référence,
+    /// ou `None` si le provider n'identifie les labels que par leur nom
+    async fn create_label(&self, label: &Label) -> Result<Option<RemoteRef>, ProviderError>;
 
-    /// Crée une nouvelle issue
-    async fn create_issue(&self, issue: &IssueCreate) -> Result<(), ProviderError>;
+    /// Met à jour la couleur et la description d'un label existant, identifié par son nom
+    async fn update_label(&self, label: &Label) -> Result<(), ProviderError>;
 
-    /// Crée un nouveau milestone et retourne son ID
-    async fn create_milestone(&self, milestone: &Milestone) -> Result<i64, ProviderError>;
+    /// `false` si les labels du provider n'ont ni couleur ni description : la
+    /// synchronisation ne compare alors que leurs noms
+    fn supports_label_colors(&self) -> bool {
+        true
+    }
 
-    /// Crée un lien entre deux issues
-    async fn create_issue_link(&self, from_id: i64, to_id: i64) -> Result<(), ProviderError>;
+    /// Nom sous lequel le provider enregistre le label `name`, comparé aux labels
+    /// existants lors de la synchronisation
+    fn label_name(&self, name: &str) -> String {
+        name.to_string()
+    }
 
-    /// Configure un projet complet avec milestones, issues et leurs relations
-    async fn setup_project(&self, project: &Project) -> Result<(), ProviderError>;
+    /// `false` si l'API du provider ne permet pas de supprimer un label : la
+    /// synchronisation ignore alors `prune`
+    fn supports_label_deletion(&self) -> bool {
+        true
+    }
+
+    /// Supprime un label par son nom
+    async fn delete_label(&self, name: &str) -> Result<(), ProviderError>;
+
+    /// Crée une nouvelle issue et retourne sa référence
+    async fn create_issue(&self, issue: &IssueCreate) -> Result<RemoteRef, ProviderError>;
+
+    /// Crée un nouveau milestone et retourne sa référence
+    async fn create_milestone(&self, milestone: &Milestone) -> Result<RemoteRef, ProviderError>;
+
+    /// Crée un lien `kind` de l'issue `from_id` vers l'issue `to_id` ; sans
+    /// équivalent natif, le provider se rabat sur la relation la plus proche
+    async fn create_issue_link(
+        &self,
+        from_id: i64,
+        to_id: i64,
+        kind: LinkKind,
+    ) -> Result<(), ProviderError>;
+
+    /// Liste les labels du repository
+    async fn list_labels(&self) -> Result<Vec<Label>, ProviderError>;
+
+    /// Liste les milestones du repository, ouverts comme fermés
+    async fn list_milestones(&self) -> Result<Vec<RemoteMilestone>, ProviderError>;
+
+    /// Liste les issues correspondant au filtre
+    async fn list_issues(&self, filter: &IssueFilter) -> Result<Vec<RemoteIssue>, ProviderError>;
+
+    /// Récupère une issue par son numéro (GitHub) ou son iid (GitLab)
+    async fn get_issue(&self, id: i64) -> Result<RemoteIssue, ProviderError>;
+
+    /// Crée une issue du fichier projet dans `milestone`, dont `milestone_id` est
+    /// l'identifiant retourné par [`Self::create_milestone`], et retourne sa référence.
+    ///
+    /// Par défaut, l'issue est créée par [`Self::create_issue`] ; les providers qui
+    /// gèrent les sprints ou les estimations la redéfinissent pour les renseigner.
+    /// L'orchestration d'un projet complet est assurée par [`crate::engine::ProjectEngine`].
+    async fn create_project_issue(
+        &self,
+        issue: &ProjectIssue,
+        milestone: &Milestone,
+        milestone_id: i64,
+    ) -> Result<RemoteRef, ProviderError> {
+        self.create_issue(&IssueCreate {
+            title: issue.title.clone(),
+            description: issue.to_markdown(),
+            labels: issue.labels.clone(),
+            milestone: Some(milestone.name.clone()),
+            milestone_id: Some(milestone_id),
+            ..IssueCreate::default()
+        })
+        .await
+    }
 }
 
 pub type DynProvider = Box<dyn RepositoryProvider>;
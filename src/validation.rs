@@ -0,0 +1,335 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt;
+
+use crate::models::common::{LinkKind, Milestone, Project, ProjectIssue};
+
+/// Problème détecté dans un fichier projet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProjectError {
+    /// Plusieurs issues portent ce titre
+    DuplicateIssue(String),
+    /// L'issue est rattachée à une version de milestone absente du projet
+    UnknownMilestone { issue: String, milestone: String },
+    /// L'issue est liée à un titre absent du projet
+    UnknownIssue {
+        issue: String,
+        target: String,
+        kind: LinkKind,
+    },
+    /// L'issue est liée à elle-même
+    SelfLink { issue: String, kind: LinkKind },
+    /// Dépendances circulaires, de la forme `A -> B -> A` où `A` attend `B`
+    Cycle(Vec<String>),
+}
+
+impl fmt::Display for ProjectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProjectError::DuplicateIssue(title) => write!(f, "Duplicate issue title \"{}\"", title),
+            ProjectError::UnknownMilestone { issue, milestone } => {
+                write!(
+                    f,
+                    "Milestone not found: {} (issue \"{}\")",
+                    milestone, issue
+                )
+            }
+            ProjectError::UnknownIssue {
+                issue,
+                target,
+                kind,
+            } => write!(
+                f,
+                "Unknown issue \"{}\" in {} link of \"{}\"",
+                target,
+                kind.name(),
+                issue
+            ),
+            ProjectError::SelfLink { issue, kind } => {
+                write!(
+                    f,
+                    "Issue \"{}\" has a {} link to itself",
+                    issue,
+                    kind.name()
+                )
+            }
+            ProjectError::Cycle(path) => {
+                let path: Vec<String> = path.iter().map(|title| format!("\"{}\"", title)).collect();
+                write!(f, "Dependency cycle: {}", path.join(" -> "))
+            }
+        }
+    }
+}
+
+/// Problèmes détectés par [`validate_project`], dans l'ordre du fichier
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationErrors(pub Vec<ProjectError>);
+
+impl ValidationErrors {
+    pub fn iter(&self) -> impl Iterator<Item = &ProjectError> {
+        self.0.iter()
+    }
+}
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} problem(s) found", self.0.len())?;
+        for error in &self.0 {
+            write!(f, "\n  - {}", error)?;
+        }
+        Ok(())
+    }
+}
+
+/// Graphe des dépendances entre les issues d'un projet, indexées dans l'ordre du fichier.
+///
+/// Une issue attend ses `dependencies`, les cibles de ses liens `blocked-by` et
+/// les issues qui la déclarent en `blocks`. Les titres inconnus et les liens
+/// d'une issue vers elle-même sont ignorés : [`validate_project`] les signale.
+#[derive(Debug, Clone)]
+pub struct DependencyGraph {
+    dependencies: Vec<Vec<usize>>,
+}
+
+impl DependencyGraph {
+    pub fn new(project: &Project) -> Self {
+        // En cas de doublon, le titre désigne sa première issue
+        let mut index: HashMap<&str, usize> = HashMap::new();
+        for (i, issue) in project.issues.iter().enumerate() {
+            index.entry(issue.title.as_str()).or_insert(i);
+        }
+
+        let mut dependencies = vec![Vec::new(); project.issues.len()];
+        for (i, issue) in project.issues.iter().enumerate() {
+            for (title, kind) in issue.relations() {
+                let Some(&target) = index.get(title) else {
+                    continue;
+                };
+                let (waiting, blocking) = match kind {
+                    LinkKind::BlockedBy => (i, target),
+                    LinkKind::Blocks => (target, i),
+                    LinkKind::RelatesTo | LinkKind::Parent | LinkKind::Child => continue,
+                };
+                if waiting != blocking && !dependencies[waiting].contains(&blocking) {
+                    dependencies[waiting].push(blocking);
+                }
+            }
+        }
+
+        Self { dependencies }
+    }
+
+    /// Index des issues attendues par l'issue `index`
+    pub fn dependencies(&self, index: usize) -> &[usize] {
+        &self.dependencies[index]
+    }
+
+    /// Ordre de création des issues : chacune après celles qu'elle attend, en
+    /// conservant autant que possible l'ordre du fichier. Les issues prises dans
+    /// un cycle sont placées à la fin, dans l'ordre du fichier.
+    pub fn topological_order(&self) -> Vec<usize> {
+        let mut waiting: Vec<usize> = self.dependencies.iter().map(Vec::len).collect();
+        let mut dependents = vec![Vec::new(); self.dependencies.len()];
+        for (node, dependencies) in self.dependencies.iter().enumerate() {
+            for &dependency in dependencies {
+                dependents[dependency].push(node);
+            }
+        }
+
+        // Parmi les issues prêtes, la première du fichier est créée d'abord
+        let mut ready: BTreeSet<usize> = (0..waiting.len()).filter(|&n| waiting[n] == 0).collect();
+        let mut order = Vec::with_capacity(waiting.len());
+        while let Some(node) = ready.pop_first() {
+            order.push(node);
+            for &dependent in &dependents[node] {
+                waiting[dependent] -= 1;
+                if waiting[dependent] == 0 {
+                    ready.insert(dependent);
+                }
+            }
+        }
+
+        if order.len() < waiting.len() {
+            let placed: HashSet<usize> = order.iter().copied().collect();
+            order.extend((0..waiting.len()).filter(|n| !placed.contains(n)));
+        }
+        order
+    }
+
+    /// Cycles du graphe, chacun sous la forme `[a, b, ..., a]`
+    pub fn cycles(&self) -> Vec<Vec<usize>> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum Visit {
+            New,
+            InProgress,
+            Done,
+        }
+
+        fn visit(
+            graph: &DependencyGraph,
+            node: usize,
+            visits: &mut [Visit],
+            path: &mut Vec<usize>,
+            cycles: &mut Vec<Vec<usize>>,
+        ) {
+            visits[node] = Visit::InProgress;
+            path.push(node);
+
+            for &next in graph.dependencies(node) {
+                match visits[next] {
+                    Visit::New => visit(graph, next, visits, path, cycles),
+                    Visit::InProgress => {
+                        // `next` est sur le chemin courant : le chemin depuis `next` boucle
+                        let start = path.iter().position(|&n| n == next).unwrap_or(0);
+                        let mut cycle = path[start..].to_vec();
+                        cycle.push(next);
+                        cycles.push(cycle);
+                    }
+                    Visit::Done => {}
+                }
+            }
+
+            path.pop();
+            visits[node] = Visit::Done;
+        }
+
+        let mut visits = vec![Visit::New; self.dependencies.len()];
+        let mut cycles = Vec::new();
+        for node in 0..self.dependencies.len() {
+            if visits[node] == Visit::New {
+                visit(self, node, &mut visits, &mut Vec::new(), &mut cycles);
+            }
+        }
+        cycles
+    }
+}
+
+/// Vérifie la cohérence d'un projet avant tout appel à l'API : titres
+/// d'issues uniques, milestones déclarés, liens vers des issues du projet
+/// et dépendances sans cycle.
+pub fn validate_project(project: &Project) -> Result<(), ValidationErrors> {
+    let mut errors = Vec::new();
+
+    let mut titles = HashSet::new();
+    let mut duplicates = HashSet::new();
+    for issue in &project.issues {
+        if !titles.insert(issue.title.as_str()) && duplicates.insert(issue.title.as_str()) {
+            errors.push(ProjectError::DuplicateIssue(issue.title.clone()));
+        }
+    }
+
+    for issue in &project.issues {
+        if !project
+            .milestones
+            .iter()
+            .any(|milestone| milestone.version == issue.milestone)
+        {
+            errors.push(ProjectError::UnknownMilestone {
+                issue: issue.title.clone(),
+                milestone: issue.milestone.clone(),
+            });
+        }
+
+        for (target, kind) in issue.relations() {
+            if target == issue.title {
+                errors.push(ProjectError::SelfLink {
+                    issue: issue.title.clone(),
+                    kind,
+                });
+            } else if !titles.contains(target) {
+                errors.push(ProjectError::UnknownIssue {
+                    issue: issue.title.clone(),
+                    target: target.to_string(),
+                    kind,
+                });
+            }
+        }
+    }
+
+    for cycle in DependencyGraph::new(project).cycles() {
+        errors.push(ProjectError::Cycle(
+            cycle
+                .into_iter()
+                .map(|i| project.issues[i].title.clone())
+                .collect(),
+        ));
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationErrors(errors))
+    }
+}
+
+/// Dépendance vers une issue planifiée après celle qui l'attend : milestone
+/// à l'échéance plus tardive ou sprint suivant
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleConflict {
+    pub issue: String,
+    /// Version du milestone de l'issue
+    pub milestone: String,
+    pub sprint: u32,
+    /// Issue attendue
+    pub dependency: String,
+    pub dependency_milestone: String,
+    pub dependency_sprint: u32,
+}
+
+impl fmt::Display for ScheduleConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "\"{}\" (milestone {}, sprint {}) depends on \"{}\" (milestone {}, sprint {})",
+            self.issue,
+            self.milestone,
+            self.sprint,
+            self.dependency,
+            self.dependency_milestone,
+            self.dependency_sprint
+        )
+    }
+}
+
+/// Dépendances planifiées après l'issue qui les attend, presque toujours une
+/// erreur de planification. Les milestones sont ordonnés par échéance
+/// (`YYYY-MM-DD`) ; une dépendance est signalée dès que son milestone ou son
+/// sprint est plus tardif. Les références inconnues sont ignorées.
+pub fn schedule_conflicts(project: &Project) -> Vec<ScheduleConflict> {
+    let milestone = |issue: &ProjectIssue| -> Option<&Milestone> {
+        project
+            .milestones
+            .iter()
+            .find(|milestone| milestone.version == issue.milestone)
+    };
+
+    let graph = DependencyGraph::new(project);
+    let mut conflicts = Vec::new();
+
+    for (index, issue) in project.issues.iter().enumerate() {
+        let Some(issue_milestone) = milestone(issue) else {
+            continue;
+        };
+
+        for &dependency_index in graph.dependencies(index) {
+            let dependency = &project.issues[dependency_index];
+            let Some(dependency_milestone) = milestone(dependency) else {
+                continue;
+            };
+
+            let scheduled_later = dependency_milestone.deadline > issue_milestone.deadline
+                || dependency.sprint > issue.sprint;
+            if scheduled_later {
+                conflicts.push(ScheduleConflict {
+                    issue: issue.title.clone(),
+                    milestone: issue.milestone.clone(),
+                    sprint: issue.sprint,
+                    dependency: dependency.title.clone(),
+                    dependency_milestone: dependency.milestone.clone(),
+                    dependency_sprint: dependency.sprint,
+                });
+            }
+        }
+    }
+
+    conflicts
+}